@@ -0,0 +1,599 @@
+use crate::config::{CommandsConfig, Images, MainConfig, Provider};
+use std::path::PathBuf;
+use typemap::Key;
+
+/// an option accepted before the command on the command line
+pub struct CliOption {
+    pub long: &'static str,
+    pub short: Option<char>,
+    /// placeholder of the value shown in help, `None` if the option does not take a value
+    pub value: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl CliOption {
+    /// how the option is shown in help, e.g. `-h, --help` or `--instance [url]`
+    fn usage(&self) -> String {
+        let mut usage = match self.short {
+            Some(short) => format!("-{short}, --{}", self.long),
+            None => format!("--{}", self.long),
+        };
+        if let Some(value) = self.value {
+            usage.push_str(&format!(" [{value}]"));
+        }
+        usage
+    }
+}
+
+pub const OPTIONS: &[CliOption] = &[
+    CliOption {
+        long: "help",
+        short: Some('h'),
+        value: None,
+        description: "Display this message",
+    },
+    CliOption {
+        long: "version",
+        short: Some('V'),
+        value: None,
+        description: "Print version info and exit",
+    },
+    CliOption {
+        long: "config-dir",
+        short: None,
+        value: Some("path"),
+        description: "Load and save config files in another directory",
+    },
+    CliOption {
+        long: "cache-dir",
+        short: None,
+        value: Some("path"),
+        description: "Store info and thumbnails of loaded items in another directory",
+    },
+    CliOption {
+        long: "data-dir",
+        short: None,
+        value: Some("path"),
+        description: "Store history, library and subscriptions in another directory",
+    },
+    CliOption {
+        long: "profile",
+        short: None,
+        value: Some("name"),
+        description: "Overlay config files with the ones in a profile",
+    },
+    CliOption {
+        long: "instance",
+        short: None,
+        value: Some("url"),
+        description: "Use another Invidious instance for this session",
+    },
+    CliOption {
+        long: "provider",
+        short: None,
+        value: Some("provider"),
+        description: "Use YouTube or Invidious urls for this session",
+    },
+    CliOption {
+        long: "offline",
+        short: None,
+        value: None,
+        description: "No background refreshing or search suggestions, opens the library on launch",
+    },
+    CliOption {
+        long: "no-images",
+        short: None,
+        value: None,
+        description: "Do not download or display thumbnails",
+    },
+    CliOption {
+        long: "launch-command",
+        short: None,
+        value: Some("command"),
+        description: "Run this command on launch instead of `launch_command` in commands.yml",
+    },
+    CliOption {
+        long: "remote",
+        short: None,
+        value: Some("command"),
+        description: "Run command in the running instance (requires `control_socket`)",
+    },
+];
+
+/// a group of commands in the help message
+pub struct HelpSection {
+    pub title: &'static str,
+    pub note: Option<&'static str>,
+    /// usage and description of each command
+    pub commands: &'static [(&'static str, &'static str)],
+}
+
+pub const HELP_SECTIONS: &[HelpSection] = &[
+    HelpSection {
+        title: "INFORMATIONAL",
+        note: None,
+        commands: &[
+            ("help", "Display this message"),
+            ("version", "Print version info and exit"),
+            (
+                "check-config",
+                "Check all config files for errors without modifying them",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "LOOKUP",
+        note: Some("print items as JSON (--json) or tab separated rows (--table) and exit"),
+        commands: &[
+            (
+                "search [query] --json",
+                "Search with the filters in search.yml",
+            ),
+            ("video [identifier] --json", "Fetch a video"),
+            (
+                "playlist [identifier] --json",
+                "Fetch a playlist with all of its videos",
+            ),
+            ("channel [identifier] --json", "Fetch a channel"),
+            ("library list", "List saved items (default: --table)"),
+        ],
+    },
+    HelpSection {
+        title: "LOADPAGE",
+        note: Some("loadpage [page] loads the corresponding page"),
+        commands: &[
+            ("loadpage popular", "Loads the popular videos page"),
+            (
+                "loadpage trending (tab)",
+                "Loads the trending videos page (now/music/gaming/news/movies)",
+            ),
+            ("loadpage watchhistory", "Loads the watch history page"),
+            ("loadpage searchhistory", "Loads the search history page"),
+            ("loadpage subscriptions", "Loads the subscriptions page"),
+            ("loadpage bookmarks", "Loads the bookmarks page"),
+            ("loadpage library", "Loads the library (saved items) page"),
+            (
+                "loadpage search [query]",
+                "Loads the search page with the given query",
+            ),
+            ("loadpage feed [name]", "Loads the feed of a saved search"),
+            ("loadpage video [identifier]", "Loads the video item page"),
+            (
+                "loadpage playlist [identifier]",
+                "Loads the playlist item page",
+            ),
+            (
+                "loadpage channel [identifier]",
+                "Loads the channel item page",
+            ),
+            (
+                "open [url]",
+                "Loads the video, playlist or channel page of any url or id",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "CHANNEL",
+        note: None,
+        commands: &[
+            (
+                "channel sort (order)",
+                "Sort channel videos by newest/popular/oldest (default: next)",
+            ),
+            (
+                "channel search [query]",
+                "Search for videos within the current channel",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "HISTORY",
+        note: None,
+        commands: &[
+            ("history back", "Revert back to previous state"),
+            (
+                "history clear",
+                "Clear all previously saved states, making the current state the original",
+            ),
+            (
+                "searchhistory clear",
+                "Remove all past searches from the search history",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "SAVED SEARCHES",
+        note: None,
+        commands: &[
+            (
+                "savesearch [name]",
+                "Save the search in the current search page as a feed",
+            ),
+            (
+                "unsavesearch (name)",
+                "Remove saved search (default: current feed)",
+            ),
+            (
+                "markread (id)",
+                "Mark result (default: hovered) as read in a feed",
+            ),
+            ("markread all", "Mark all results as read in a feed"),
+        ],
+    },
+    HelpSection {
+        title: "UTILITY",
+        note: None,
+        commands: &[
+            ("reload", "Reloads the current page"),
+            ("reload configs", "Reload all config files"),
+            (
+                "profile (name)",
+                "Switch to a config profile, `default` for none (default: list profiles)",
+            ),
+            ("flush", "Run all tasks in queue immediately"),
+            ("quit", "Immediately exit"),
+            (
+                "run [command]",
+                "Runs a system command (e.g. `run rm -rf / --no-preserve-root`)",
+            ),
+            (
+                "parrun [command]",
+                "Runs a system command non blocking (e.g. `run firefox example.com`)",
+            ),
+            ("copy [text]", "Copies text to clipboard"),
+            (
+                "region (code)",
+                "Sets the region of trending videos (e.g. `region GB`)",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "LIBRARY",
+        note: None,
+        commands: &[
+            (
+                "bookmark [id]",
+                "Bookmark item with ID (item must be already loaded)",
+            ),
+            ("unmark [id]", "Remove bookmark item with ID"),
+            ("togglemark [id]", "Toggle bookmark status"),
+        ],
+    },
+    HelpSection {
+        title: "SUBSCRIPTIONS",
+        note: None,
+        commands: &[
+            (
+                "subscribe [identifier]",
+                "Subscribe to channel with ID or url",
+            ),
+            ("unsubscribe [id]", "Unsubscribe from channel with ID"),
+            ("togglesub [id]", "Toggle subscription status"),
+            (
+                "markread (id)",
+                "Mark video (default: hovered) and older videos from its channel as read",
+            ),
+            (
+                "markread all",
+                "Mark all videos in the subscriptions feed as read",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "FILTERS",
+        note: None,
+        commands: &[
+            ("block channel [id]", "Hide all items from channel with ID"),
+            ("unblock channel [id]", "Unblock channel with ID"),
+            (
+                "togglewatched",
+                "Toggle hiding watched videos in the current page",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "ALT",
+        note: Some("alts links back to the original command"),
+        commands: &[
+            ("[page] (additional options)", "`loadpage [page]`"),
+            ("back", "`history back`"),
+            ("r", "`reload`"),
+            ("reload/r config/configs", "`reload configs`"),
+            ("q, exit, x", "`quit`"),
+            ("cp [text]", "`copy [text]`"),
+        ],
+    },
+];
+
+/// generates the help message from `OPTIONS` and `HELP_SECTIONS`
+pub fn help_message() -> String {
+    let options = OPTIONS.iter().map(CliOption::usage).collect::<Vec<_>>();
+    // descriptions are aligned to the longest usage
+    let width = options
+        .iter()
+        .map(String::len)
+        .chain(
+            HELP_SECTIONS
+                .iter()
+                .flat_map(|section| section.commands.iter().map(|(usage, _)| usage.len())),
+        )
+        .max()
+        .unwrap_or_default()
+        + 2;
+    let line = |usage: &str, description: &str| {
+        format!("    \x1b[33m{usage:width$}\x1b[0m{description}\n")
+    };
+
+    let mut message = String::from(
+        "\x1b[32mYouTube TUI commands\x1b[0m
+
+\x1b[37mfor more visit https://siriusmart.github.io/youtube-tui/commands.html\x1b[0m
+
+\x1b[91mUSAGE:\x1b[0m
+    youtube-tui [options] (command)
+    youtube-tui --remote [command]
+    youtube-tui [url]
+
+\x1b[91mOPTIONS:\x1b[0m
+",
+    );
+    OPTIONS
+        .iter()
+        .zip(options.iter())
+        .for_each(|(option, usage)| message.push_str(&line(usage, option.description)));
+
+    for section in HELP_SECTIONS {
+        message.push_str(&format!("\n\x1b[91m{}:\x1b[0m\n", section.title));
+        if let Some(note) = section.note {
+            message.push_str(&format!("\x1b[37m{note}\x1b[0m\n"));
+        }
+        section
+            .commands
+            .iter()
+            .for_each(|(usage, description)| message.push_str(&line(usage, description)));
+    }
+
+    message.push_str("\n\x1b[37mOnly load page, lookup and informational commands can be used from command line, the rest can only be used in (`:`) command mode inside the TUI.\x1b[0m");
+    message
+}
+
+/// parsed command line arguments, stored in `data.global` so that the overrides are applied
+/// again when config files are reloaded
+#[derive(Clone, Default)]
+pub struct CliArgs {
+    pub config_dir: Option<PathBuf>,
+    pub cache_dir: Option<PathBuf>,
+    pub data_dir: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub instance: Option<String>,
+    pub provider: Option<Provider>,
+    pub offline: bool,
+    pub no_images: bool,
+    pub launch_command: Option<String>,
+    /// command to send to the running instance
+    pub remote: Option<String>,
+    /// everything after the options, such as `loadpage popular` or `help`
+    pub command: String,
+}
+
+impl Key for CliArgs {
+    type Value = Self;
+}
+
+impl CliArgs {
+    /// options are only read before the command, so that commands like `run mpv --no-video` are
+    /// passed through unchanged
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut cli = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                break;
+            }
+            if !arg.starts_with('-') || arg == "-" {
+                cli.command = arg;
+                break;
+            }
+
+            // both `--name value` and `--name=value` are accepted
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            let option = OPTIONS
+                .iter()
+                .find(|option| {
+                    name.strip_prefix("--") == Some(option.long)
+                        || option
+                            .short
+                            .is_some_and(|short| name == format!("-{short}"))
+                })
+                .ok_or_else(|| format!("Unknown option `{name}`, see `youtube-tui --help`"))?;
+
+            if option.value.is_none() && inline_value.is_some() {
+                return Err(format!("`--{}` does not take a value", option.long));
+            }
+
+            // `--remote` takes everything after it as the command
+            if option.long == "remote" {
+                let command = inline_value
+                    .into_iter()
+                    .chain(args.by_ref())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if command.is_empty() {
+                    return Err(String::from("`--remote` requires a command"));
+                }
+                cli.remote = Some(command);
+                return Ok(cli);
+            }
+
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("`--{}` requires a value", option.long))
+            };
+
+            match option.long {
+                "help" => cli.command = String::from("help"),
+                "version" => cli.command = String::from("version"),
+                "config-dir" => cli.config_dir = Some(PathBuf::from(value()?)),
+                "cache-dir" => cli.cache_dir = Some(PathBuf::from(value()?)),
+                "data-dir" => cli.data_dir = Some(PathBuf::from(value()?)),
+                "profile" => cli.profile = Some(value()?),
+                "instance" => cli.instance = Some(value()?.trim_end_matches('/').to_string()),
+                "provider" => {
+                    cli.provider = Some(match value()?.to_lowercase().as_str() {
+                        "youtube" => Provider::YouTube,
+                        "invidious" => Provider::Invidious,
+                        provider => {
+                            return Err(format!(
+                                "Unknown provider `{provider}`, use `YouTube` or `Invidious`"
+                            ))
+                        }
+                    })
+                }
+                "offline" => cli.offline = true,
+                "no-images" => cli.no_images = true,
+                "launch-command" => cli.launch_command = Some(value()?),
+                _ => unreachable!(),
+            }
+
+            if ["help", "version"].contains(&option.long) {
+                return Ok(cli);
+            }
+        }
+
+        let rest = args.collect::<Vec<_>>();
+        if !rest.is_empty() {
+            if !cli.command.is_empty() {
+                cli.command.push(' ');
+            }
+            cli.command.push_str(&rest.join(" "));
+        }
+
+        Ok(cli)
+    }
+
+    /// overrides options in main config for this session, they are never saved to `main.yml`
+    pub fn apply(&self, mainconfig: &mut MainConfig) {
+        if let Some(instance) = &self.instance {
+            mainconfig.invidious_instance = instance.clone();
+        }
+        if let Some(provider) = self.provider {
+            mainconfig.provider = provider;
+        }
+        if self.no_images {
+            mainconfig.images = Images::None;
+        }
+        if self.offline {
+            mainconfig.refresh_interval = 0;
+            mainconfig.search_suggestions = false;
+        }
+    }
+
+    /// the command to run when the TUI is launched
+    pub fn startup_command(&self, commands: &CommandsConfig) -> String {
+        if !self.command.is_empty() {
+            self.command.clone()
+        } else if let Some(command) = &self.launch_command {
+            command.clone()
+        } else if self.offline {
+            // saved items are the only ones available without a connection
+            String::from("loadpage library")
+        } else {
+            commands.launch_command.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<CliArgs, String> {
+        CliArgs::parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parses_option_values() {
+        for args in [
+            "--profile work --instance https://example.com/ --config-dir /tmp/config",
+            "--profile=work --instance=https://example.com/ --config-dir=/tmp/config",
+        ] {
+            let cli = parse(args).unwrap();
+            assert_eq!(cli.profile.as_deref(), Some("work"), "{args}");
+            assert_eq!(
+                cli.instance.as_deref(),
+                Some("https://example.com"),
+                "{args}"
+            );
+            assert_eq!(cli.config_dir, Some(PathBuf::from("/tmp/config")), "{args}");
+            assert!(cli.command.is_empty(), "{args}");
+        }
+
+        let cli = parse("--provider YouTube --offline --no-images").unwrap();
+        assert!(cli.provider == Some(Provider::YouTube));
+        assert!(cli.offline);
+        assert!(cli.no_images);
+    }
+
+    #[test]
+    fn parses_short_flags() {
+        assert_eq!(parse("-h").unwrap().command, "help");
+        assert_eq!(parse("-V").unwrap().command, "version");
+        // options after help are not read
+        assert_eq!(parse("--help --unknown").unwrap().command, "help");
+    }
+
+    #[test]
+    fn stops_at_the_command() {
+        let cli = parse("--offline run mpv --no-video").unwrap();
+        assert!(cli.offline);
+        assert_eq!(cli.command, "run mpv --no-video");
+
+        let cli = parse("--profile work -- --offline").unwrap();
+        assert_eq!(cli.profile.as_deref(), Some("work"));
+        assert!(!cli.offline);
+        assert_eq!(cli.command, "--offline");
+
+        assert_eq!(parse("- loadpage").unwrap().command, "- loadpage");
+    }
+
+    #[test]
+    fn remote_takes_the_rest() {
+        let cli = parse("--offline --remote loadpage --offline popular").unwrap();
+        assert!(cli.offline);
+        assert_eq!(cli.remote.as_deref(), Some("loadpage --offline popular"));
+        assert!(cli.command.is_empty());
+
+        let cli = parse("--remote=search rust").unwrap();
+        assert_eq!(cli.remote.as_deref(), Some("search rust"));
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        for (args, error) in [
+            (
+                "--unknown",
+                "Unknown option `--unknown`, see `youtube-tui --help`",
+            ),
+            ("-x", "Unknown option `-x`, see `youtube-tui --help`"),
+            (
+                "--unknown=value",
+                "Unknown option `--unknown`, see `youtube-tui --help`",
+            ),
+            ("--offline=yes", "`--offline` does not take a value"),
+            ("--profile", "`--profile` requires a value"),
+            ("--remote", "`--remote` requires a command"),
+            (
+                "--provider piped",
+                "Unknown provider `piped`, use `YouTube` or `Invidious`",
+            ),
+        ] {
+            assert_eq!(parse(args).err().as_deref(), Some(error), "{args}");
+        }
+    }
+}
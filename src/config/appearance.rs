@@ -1,80 +1,49 @@
-use crate::{config::serde::*, global::traits::*};
-use home::home_dir;
-use serde::{Deserialize, Serialize};
-use std::{
-    error::Error,
-    fs::{self, OpenOptions},
-    io::Write,
+use crate::{
+    config::serde::*,
+    global::{structs::ConfigIssue, traits::*},
 };
+use serde::{Deserialize, Serialize};
+use std::error::Error;
 use tui::{style::Color, widgets::BorderType};
 use typemap::Key;
 
 use super::WriteConfig;
 
 /// `appearance.yml`, impl serde version of AppearanceConfig
-#[derive(Default, Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct AppearanceConfigSerde {
     #[serde(default)]
     pub borders: BorderTypeSerde,
+    #[serde(default = "watched_marker_default")]
+    // prefix of watched items in item lists
+    pub watched_marker: String,
     #[serde(default)]
     pub colors: ColorsConfigSerde,
 }
 
+impl Default for AppearanceConfigSerde {
+    fn default() -> Self {
+        Self {
+            borders: BorderTypeSerde::default(),
+            watched_marker: watched_marker_default(),
+            colors: ColorsConfigSerde::default(),
+        }
+    }
+}
+
 /// `appearance.yml`, this struct is stored in `data.global`
 #[derive(Clone)]
 pub struct AppearanceConfig {
     pub borders: BorderType,
+    pub watched_marker: String,
     pub colors: ColorsConfig,
 }
 
 impl AppearanceConfig {
-    /// generates a new file if the original one is invalid
+    /// reports the invalid color if the file cannot be converted
     pub fn load(write: WriteConfig) -> Result<Self, Box<dyn Error>> {
         let serde = *AppearanceConfigSerde::load(write)?;
-        let try_into = Self::from(serde);
-
-        // check if it be converted from `AppearanceConfigSerde` to `AppearanceConfig`
-        if let Some(appearance) = try_into {
-            return Ok(appearance);
-        }
-
-        let config_path = home_dir().unwrap().join(format!(
-            ".config/youtube-tui/{}.{}",
-            AppearanceConfigSerde::LABEL,
-            EXTENSION
-        ));
-
-        // if it cannot, back it up and regenerate it
-        let mut new_path = config_path.clone();
-        new_path.pop();
-        new_path.push(format!(
-            "{}-{}.{}",
-            AppearanceConfigSerde::LABEL,
-            chrono::offset::Local::now(),
-            EXTENSION
-        ));
-        fs::rename(&config_path, &new_path)?;
-
-        // here generates a default and write it to the file
-        let default = AppearanceConfigSerde::default();
-
-        let mut file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&config_path)?;
-
-        file.write_all(serde_yaml::to_string(&default)?.as_bytes())?;
-
-        match Self::from(default) {
-            Some(appearance) => Ok(appearance),
-            // if the default still cannot be converted, panic and exit the program
-            None => panic!(
-                "the default for {}.{} is invalid",
-                AppearanceConfigSerde::LABEL,
-                EXTENSION
-            ),
-        }
+        Ok(Self::from(serde).map_err(|issue| issue.in_config(AppearanceConfigSerde::LABEL))?)
     }
 }
 
@@ -93,6 +62,7 @@ pub struct ColorsConfig {
     pub message_error_outline: Color,
     pub message_success_outline: Color,
     pub command_capture: Color,
+    pub watched: Color,
     pub item_info: ItemInfoColors,
 }
 
@@ -111,6 +81,7 @@ pub struct ItemInfoColors {
     pub likes: Color,
     pub genre: Color,
     pub page_turner: Color,
+    pub unread: Color,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -139,6 +110,8 @@ pub struct ColorsConfigSerde {
     pub message_success_outline: ColorSerde,
     #[serde(default = "command_capture_default")]
     pub command_capture: ColorSerde,
+    #[serde(default = "watched_default")]
+    pub watched: ColorSerde,
     #[serde(default)]
     pub item_info: ItemInfoColorsSerde,
 }
@@ -169,49 +142,70 @@ pub struct ItemInfoColorsSerde {
     pub genre: ColorSerde,
     #[serde(default = "page_turner_default")]
     pub page_turner: ColorSerde,
+    #[serde(default = "unread_default")]
+    pub unread: ColorSerde,
 }
 
-// uses a custom `into` for Option<T> instead of T so that we can know that the config is invalid
+// uses a custom `into` for Result<T> instead of T so that we can know which color is invalid
 // without panicking
 impl ColorsConfigSerde {
-    pub fn into(self) -> Option<ColorsConfig> {
-        Some(ColorsConfig {
-            text: self.text.to_color()?,
-            text_special: self.text_special.to_color()?,
-            text_secondary: self.text_secondary.to_color()?,
-            text_error: self.text_error.to_color()?,
-            outline: self.outline.to_color()?,
-            outline_selected: self.outline_selected.to_color()?,
-            outline_hover: self.outline_hover.to_color()?,
-            outline_secondary: self.outline_secondary.to_color()?,
-            message_outline: self.message_outline.to_color()?,
-            message_error_outline: self.message_error_outline.to_color()?,
-            message_success_outline: self.message_success_outline.to_color()?,
-            command_capture: self.command_capture.to_color()?,
+    pub fn into(self) -> Result<ColorsConfig, ConfigIssue> {
+        Ok(ColorsConfig {
+            text: to_color(self.text, &["colors", "text"])?,
+            text_special: to_color(self.text_special, &["colors", "text_special"])?,
+            text_secondary: to_color(self.text_secondary, &["colors", "text_secondary"])?,
+            text_error: to_color(self.text_error, &["colors", "text_error"])?,
+            outline: to_color(self.outline, &["colors", "outline"])?,
+            outline_selected: to_color(self.outline_selected, &["colors", "outline_selected"])?,
+            outline_hover: to_color(self.outline_hover, &["colors", "outline_hover"])?,
+            outline_secondary: to_color(self.outline_secondary, &["colors", "outline_secondary"])?,
+            message_outline: to_color(self.message_outline, &["colors", "message_outline"])?,
+            message_error_outline: to_color(
+                self.message_error_outline,
+                &["colors", "message_error_outline"],
+            )?,
+            message_success_outline: to_color(
+                self.message_success_outline,
+                &["colors", "message_success_outline"],
+            )?,
+            command_capture: to_color(self.command_capture, &["colors", "command_capture"])?,
+            watched: to_color(self.watched, &["colors", "watched"])?,
             item_info: self.item_info.into()?,
         })
     }
 }
 
 impl ItemInfoColorsSerde {
-    pub fn into(self) -> Option<ItemInfoColors> {
-        Some(ItemInfoColors {
-            tag: self.tag.to_color()?,
-            title: self.title.to_color()?,
-            description: self.description.to_color()?,
-            author: self.author.to_color()?,
-            viewcount: self.viewcount.to_color()?,
-            length: self.length.to_color()?,
-            published: self.published.to_color()?,
-            video_count: self.video_count.to_color()?,
-            sub_count: self.sub_count.to_color()?,
-            likes: self.likes.to_color()?,
-            genre: self.genre.to_color()?,
-            page_turner: self.page_turner.to_color()?,
+    pub fn into(self) -> Result<ItemInfoColors, ConfigIssue> {
+        Ok(ItemInfoColors {
+            tag: to_color(self.tag, &["colors", "item_info", "tag"])?,
+            title: to_color(self.title, &["colors", "item_info", "title"])?,
+            description: to_color(self.description, &["colors", "item_info", "description"])?,
+            author: to_color(self.author, &["colors", "item_info", "author"])?,
+            viewcount: to_color(self.viewcount, &["colors", "item_info", "viewcount"])?,
+            length: to_color(self.length, &["colors", "item_info", "length"])?,
+            published: to_color(self.published, &["colors", "item_info", "published"])?,
+            video_count: to_color(self.video_count, &["colors", "item_info", "video_count"])?,
+            sub_count: to_color(self.sub_count, &["colors", "item_info", "sub_count"])?,
+            likes: to_color(self.likes, &["colors", "item_info", "likes"])?,
+            genre: to_color(self.genre, &["colors", "item_info", "genre"])?,
+            page_turner: to_color(self.page_turner, &["colors", "item_info", "page_turner"])?,
+            unread: to_color(self.unread, &["colors", "item_info", "unread"])?,
         })
     }
 }
 
+/// converts a color, `key` is reported if it is invalid
+fn to_color(color: ColorSerde, key: &[&str]) -> Result<Color, ConfigIssue> {
+    let text = match &color {
+        ColorSerde::Hex(hex) => hex.clone(),
+        ColorSerde::ColorVariant(_) => String::new(),
+    };
+    color
+        .to_color()
+        .ok_or_else(|| ConfigIssue::new(key, format!("`{text}` is not a valid color")))
+}
+
 impl Default for ColorsConfigSerde {
     fn default() -> Self {
         Self {
@@ -227,6 +221,7 @@ impl Default for ColorsConfigSerde {
             message_success_outline: message_success_outline_default(),
             text_error: text_error_default(),
             command_capture: command_capture_default(),
+            watched: watched_default(),
             item_info: ItemInfoColorsSerde::default(),
         }
     }
@@ -247,14 +242,16 @@ impl Default for ItemInfoColorsSerde {
             likes: likes_default(),
             genre: genre_default(),
             page_turner: page_turner_default(),
+            unread: unread_default(),
         }
     }
 }
 
 impl AppearanceConfig {
-    fn from(original: AppearanceConfigSerde) -> Option<Self> {
-        Some(Self {
+    pub fn from(original: AppearanceConfigSerde) -> Result<Self, ConfigIssue> {
+        Ok(Self {
             borders: original.borders.into(),
+            watched_marker: original.watched_marker,
             colors: original.colors.into()?,
         })
     }
@@ -362,6 +359,18 @@ fn page_turner_default() -> ColorSerde {
     ColorSerde::ColorVariant(ColorVariantSerde::Gray)
 }
 
+fn unread_default() -> ColorSerde {
+    ColorSerde::ColorVariant(ColorVariantSerde::LightGreen)
+}
+
 fn command_capture_default() -> ColorSerde {
     ColorSerde::Hex(String::from("#64FF64"))
 }
+
+fn watched_default() -> ColorSerde {
+    ColorSerde::ColorVariant(ColorVariantSerde::DarkGray)
+}
+
+fn watched_marker_default() -> String {
+    String::from("[watched] ")
+}
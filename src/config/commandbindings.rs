@@ -19,6 +19,8 @@ pub struct CommandBindings {
     pub channel_videos: HashMap<KeyCode, HashMap<u8, String>>,
     pub channel_playlists: HashMap<KeyCode, HashMap<u8, String>>,
     pub watchhistory: HashMap<KeyCode, HashMap<u8, String>>,
+    pub searchhistory: HashMap<KeyCode, HashMap<u8, String>>,
+    pub savedsearch: HashMap<KeyCode, HashMap<u8, String>>,
     pub subscriptions: HashMap<KeyCode, HashMap<u8, String>>,
     pub libray: HashMap<KeyCode, HashMap<u8, String>>,
 }
@@ -37,19 +39,27 @@ impl CommandBindings {
 
         let command = match page {
             Page::Search(_) => get_command(key, &self.search),
-            Page::MainMenu(MainMenuPage::Trending) => get_command(key, &self.trending),
+            Page::MainMenu(MainMenuPage::Trending(_)) => get_command(key, &self.trending),
             Page::MainMenu(MainMenuPage::Popular) => get_command(key, &self.popular),
             Page::MainMenu(MainMenuPage::History) => get_command(key, &self.watchhistory),
+            Page::MainMenu(MainMenuPage::SearchHistory) => get_command(key, &self.searchhistory),
+            Page::MainMenu(MainMenuPage::SavedSearch(_)) => get_command(key, &self.savedsearch),
             Page::MainMenu(MainMenuPage::Subscriptions) => get_command(key, &self.subscriptions),
             Page::MainMenu(MainMenuPage::Library) => get_command(key, &self.libray),
-            Page::SingleItem(SingleItemPage::Video(_)) => get_command(key, &self.video),
+            Page::SingleItem(SingleItemPage::Video(_, _)) => get_command(key, &self.video),
             Page::SingleItem(SingleItemPage::Playlist(_)) => get_command(key, &self.playlist),
+            // the community tab shares bindings with the main channel page, and the shorts, streams
+            // and search tabs with the videos page
             Page::ChannelDisplay(ChannelDisplayPage {
-                r#type: ChannelDisplayPageType::Main,
+                r#type: ChannelDisplayPageType::Main | ChannelDisplayPageType::Community,
                 ..
             }) => get_command(key, &self.channel_main),
             Page::ChannelDisplay(ChannelDisplayPage {
-                r#type: ChannelDisplayPageType::Videos,
+                r#type:
+                    ChannelDisplayPageType::Videos
+                    | ChannelDisplayPageType::Shorts
+                    | ChannelDisplayPageType::Streams
+                    | ChannelDisplayPageType::Search,
                 ..
             }) => get_command(key, &self.channel_videos),
             Page::ChannelDisplay(ChannelDisplayPage {
@@ -91,6 +101,10 @@ pub struct CommandBindingsSerde {
     pub channel_playlists: HashMap<KeyCodeSerde, HashMap<u8, String>>,
     #[serde(default = "watchhistory_default")]
     pub watchhistory: HashMap<KeyCodeSerde, HashMap<u8, String>>,
+    #[serde(default = "searchhistory_default")]
+    pub searchhistory: HashMap<KeyCodeSerde, HashMap<u8, String>>,
+    #[serde(default = "savedsearch_default")]
+    pub savedsearch: HashMap<KeyCodeSerde, HashMap<u8, String>>,
     #[serde(default = "subscriptions_default")]
     pub subscriptions: HashMap<KeyCodeSerde, HashMap<u8, String>>,
     #[serde(default = "library_default")]
@@ -102,20 +116,22 @@ impl ConfigTrait for CommandBindingsSerde {
 }
 
 impl CommandBindingsSerde {
-    pub fn into(self) -> Option<CommandBindings> {
-        Some(CommandBindings {
-            global: de_serde(self.global)?,
-            search: de_serde(self.search)?,
-            popular: de_serde(self.popular)?,
-            channel_main: de_serde(self.channel_main)?,
-            channel_videos: de_serde(self.channel_videos)?,
-            channel_playlists: de_serde(self.channel_playlists)?,
-            playlist: de_serde(self.playlist)?,
-            video: de_serde(self.video)?,
-            trending: de_serde(self.trending)?,
-            watchhistory: de_serde(self.watchhistory)?,
-            subscriptions: de_serde(self.subscriptions)?,
-            libray: de_serde(self.library)?,
+    pub fn into(self) -> Result<CommandBindings, ConfigIssue> {
+        Ok(CommandBindings {
+            global: de_serde(self.global, "global")?,
+            search: de_serde(self.search, "search")?,
+            popular: de_serde(self.popular, "popular")?,
+            channel_main: de_serde(self.channel_main, "channel_main")?,
+            channel_videos: de_serde(self.channel_videos, "channel_videos")?,
+            channel_playlists: de_serde(self.channel_playlists, "channel_playlists")?,
+            playlist: de_serde(self.playlist, "playlist")?,
+            video: de_serde(self.video, "video")?,
+            trending: de_serde(self.trending, "trending")?,
+            watchhistory: de_serde(self.watchhistory, "watchhistory")?,
+            searchhistory: de_serde(self.searchhistory, "searchhistory")?,
+            savedsearch: de_serde(self.savedsearch, "savedsearch")?,
+            subscriptions: de_serde(self.subscriptions, "subscriptions")?,
+            libray: de_serde(self.library, "library")?,
         })
     }
 }
@@ -133,6 +149,8 @@ impl Default for CommandBindingsSerde {
             video: video_default(),
             trending: trending_default(),
             watchhistory: watchhistory_default(),
+            searchhistory: searchhistory_default(),
+            savedsearch: savedsearch_default(),
             subscriptions: subscriptions_default(),
             library: library_default(),
         }
@@ -141,15 +159,16 @@ impl Default for CommandBindingsSerde {
 
 fn de_serde(
     original: HashMap<KeyCodeSerde, HashMap<u8, String>>,
-) -> Option<HashMap<KeyCode, HashMap<u8, String>>> {
+    page: &str,
+) -> Result<HashMap<KeyCode, HashMap<u8, String>>, ConfigIssue> {
     let mut out = HashMap::new();
 
     // simply loops over the serde hashmap and insert them into the new one
     for (keycodeserde, map) in original.into_iter() {
-        out.insert(keycodeserde.to_keycode()?, map);
+        out.insert(keycodeserde.try_keycode(&[page])?, map);
     }
 
-    Some(out)
+    Ok(out)
 }
 
 fn get_command<'a>(
@@ -176,6 +195,7 @@ fn global_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
 
 fn search_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
     HashMap::from([
+        (KeyCodeSerde::Char('w'), HashMap::from([(2, String::from("togglewatched"))])),
         (KeyCodeSerde::Char('a'), HashMap::from([(2, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video"))])),
         (KeyCodeSerde::Char('A'), HashMap::from([(1, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video --loop-playlist=inf --shuffle"))])),
         (KeyCodeSerde::Char('p'), HashMap::from([(2, String::from("parrun mpv '${hover-url}'"))])),
@@ -183,11 +203,15 @@ fn search_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
 }
 
 fn channel_main_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
-    HashMap::default()
+    HashMap::from([(
+        KeyCodeSerde::Char('s'),
+        HashMap::from([(2, String::from("togglesub ${channel-id}"))]),
+    )])
 }
 
 fn channel_playlists_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
     HashMap::from([
+        (KeyCodeSerde::Char('o'), HashMap::from([(2, String::from("channel sort"))])),
         (KeyCodeSerde::Char('a'), HashMap::from([(2, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video"))])),
         (KeyCodeSerde::Char('A'), HashMap::from([(1, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video --loop-playlist=inf --shuffle"))])),
         (KeyCodeSerde::Char('p'), HashMap::from([(2, String::from("parrun mpv '${hover-url}'"))])),
@@ -196,6 +220,8 @@ fn channel_playlists_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
 
 fn channel_videos_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
     HashMap::from([
+        (KeyCodeSerde::Char('o'), HashMap::from([(2, String::from("channel sort"))])),
+        (KeyCodeSerde::Char('w'), HashMap::from([(2, String::from("togglewatched"))])),
         (KeyCodeSerde::Char('a'), HashMap::from([(2, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video"))])),
         (KeyCodeSerde::Char('A'), HashMap::from([(1, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video --loop-playlist=inf --shuffle"))])),
         (KeyCodeSerde::Char('p'), HashMap::from([(2, String::from("parrun mpv '${hover-url}'"))])),
@@ -212,6 +238,7 @@ fn playlist_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
 
 fn popular_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
     HashMap::from([
+        (KeyCodeSerde::Char('w'), HashMap::from([(2, String::from("togglewatched"))])),
         (KeyCodeSerde::Char('a'), HashMap::from([(2, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video"))])),
         (KeyCodeSerde::Char('A'), HashMap::from([(1, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video --loop-playlist=inf --shuffle"))])),
         (KeyCodeSerde::Char('p'), HashMap::from([(2, String::from("parrun mpv '${hover-url}'"))])),
@@ -220,6 +247,7 @@ fn popular_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
 
 fn trending_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
     HashMap::from([
+        (KeyCodeSerde::Char('w'), HashMap::from([(2, String::from("togglewatched"))])),
         (KeyCodeSerde::Char('a'), HashMap::from([(2, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video"))])),
         (KeyCodeSerde::Char('A'), HashMap::from([(1, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video --loop-playlist=inf --shuffle"))])),
         (KeyCodeSerde::Char('p'), HashMap::from([(2, String::from("parrun mpv '${hover-url}'"))])),
@@ -238,10 +266,29 @@ fn watchhistory_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
     ])
 }
 
-fn subscriptions_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
+fn searchhistory_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
     HashMap::default()
 }
 
+fn savedsearch_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
+    HashMap::from([
+        (KeyCodeSerde::Char('R'), HashMap::from([(1, String::from("markread ${hover-id}"))])),
+        (KeyCodeSerde::Char('w'), HashMap::from([(2, String::from("togglewatched"))])),
+        (KeyCodeSerde::Char('a'), HashMap::from([(2, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video"))])),
+        (KeyCodeSerde::Char('A'), HashMap::from([(1, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video --loop-playlist=inf --shuffle"))])),
+        (KeyCodeSerde::Char('p'), HashMap::from([(2, String::from("parrun mpv '${hover-url}'"))]))
+    ])
+}
+
+fn subscriptions_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
+    HashMap::from([
+        (KeyCodeSerde::Char('R'), HashMap::from([(1, String::from("markread ${hover-id}"))])),
+        (KeyCodeSerde::Char('a'), HashMap::from([(2, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video"))])),
+        (KeyCodeSerde::Char('A'), HashMap::from([(1, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video --loop-playlist=inf --shuffle"))])),
+        (KeyCodeSerde::Char('p'), HashMap::from([(2, String::from("parrun mpv '${hover-url}'"))]))
+    ])
+}
+
 fn library_default() -> HashMap<KeyCodeSerde, HashMap<u8, String>> {
     HashMap::from([
         (KeyCodeSerde::Char('a'), HashMap::from([(2, String::from("parrun ${terminal-emulator} mpv '${hover-url}' --no-video"))])),
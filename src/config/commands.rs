@@ -90,7 +90,7 @@ fn video_default() -> Vec<HashMap<String, String>> {
     vec![
         HashMap::from([(
             String::from("Reload updated video"),
-            String::from("run rm '~/.cache/youtube-tui/info/${id}.json' ;; video ${id}"),
+            String::from("run rm '${cache-dir}/info/${id}.json' ;; video ${id}"),
         )]),
         HashMap::from([(
             String::from("Play video"),
@@ -137,7 +137,7 @@ fn saved_video_default() -> Vec<HashMap<String, String>> {
     vec![
         HashMap::from([(
             String::from("Reload updated video"),
-            String::from("run rm '~/.cache/youtube-tui/info/${id}.json' ;; video ${id}"),
+            String::from("run rm '${cache-dir}/info/${id}.json' ;; video ${id}"),
         )]),
         HashMap::from([(
             String::from("[Offline] Play saved file"),
@@ -183,7 +183,7 @@ fn playlist_default() -> Vec<HashMap<String, String>> {
         HashMap::from([(String::from("Switch view"), String::from("%switch-view%"))]),
         HashMap::from([(
             String::from("Reload updated playlist"),
-            String::from("run rm ~/.cache/youtube-tui/info/${id}.json ;; reload"),
+            String::from("run rm '${cache-dir}/info/${id}.json' ;; reload"),
         )]),
         HashMap::from([(
             String::from("Play all (videos)"),
@@ -229,7 +229,7 @@ fn saved_playlist_default() -> Vec<HashMap<String, String>> {
         HashMap::from([(String::from("Switch view"), String::from("%switch-view%"))]),
         HashMap::from([(
             String::from("Reload updated playlist"),
-            String::from("run rm ~/.cache/youtube-tui/info/${id}.json ;; reload"),
+            String::from("run rm '${cache-dir}/info/${id}.json' ;; reload"),
         )]),
         HashMap::from([(
             String::from("[Offline] Play all (videos)"),
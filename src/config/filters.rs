@@ -0,0 +1,171 @@
+use crate::global::{structs::*, traits::ConfigTrait};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use typemap::Key;
+
+/// `filters.yml`, items matching any of the filters are hidden from all lists
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FiltersConfig {
+    #[serde(default)]
+    pub blocked_channels: Vec<String>,
+    #[serde(default)]
+    // case insensitive, matched against titles and channel names
+    pub blocked_keywords: Vec<String>,
+    #[serde(default)]
+    // regular expressions matched against titles and channel names
+    pub blocked_patterns: Vec<String>,
+    #[serde(default)]
+    // in seconds, 0 to disable
+    pub min_length: u32,
+    #[serde(default)]
+    pub hide_shorts: bool,
+    #[serde(default = "shorts_max_length_default")]
+    // in seconds, videos this long or shorter are hidden by `hide_shorts`
+    pub shorts_max_length: u32,
+    #[serde(default)]
+    pub hide_live: bool,
+    // compiled `blocked_patterns`, invalid patterns are skipped
+    #[serde(skip)]
+    pub regexes: Vec<Regex>,
+}
+
+impl Default for FiltersConfig {
+    fn default() -> Self {
+        Self {
+            blocked_channels: Vec::new(),
+            blocked_keywords: Vec::new(),
+            blocked_patterns: Vec::new(),
+            min_length: 0,
+            hide_shorts: false,
+            shorts_max_length: shorts_max_length_default(),
+            hide_live: false,
+            regexes: Vec::new(),
+        }
+    }
+}
+
+impl Key for FiltersConfig {
+    type Value = Self;
+}
+
+impl ConfigTrait for FiltersConfig {
+    const LABEL: &'static str = "filters";
+}
+
+impl FiltersConfig {
+    /// compile `blocked_patterns` into `regexes`, returns an issue for each pattern that is not
+    /// valid
+    pub fn compile(&mut self) -> Vec<ConfigIssue> {
+        let mut invalid = Vec::new();
+        self.regexes = self
+            .blocked_patterns
+            .iter()
+            .filter_map(|pattern| match Regex::new(pattern) {
+                Ok(regex) => Some(regex),
+                Err(_) => {
+                    invalid.push(
+                        ConfigIssue::new(
+                            &["blocked_patterns"],
+                            format!("`{pattern}` is not a valid regular expression"),
+                        )
+                        .in_config(Self::LABEL),
+                    );
+                    None
+                }
+            })
+            .collect();
+        invalid
+    }
+
+    /// returns true if the item should be displayed
+    pub fn allows(&self, item: &Item) -> bool {
+        let (channel_id, texts, length): (&str, [&str; 2], Option<&str>) = match item {
+            Item::MiniVideo(MiniVideoItem {
+                title,
+                channel,
+                channel_id,
+                length,
+                ..
+            })
+            | Item::FullVideo(FullVideoItem {
+                title,
+                channel,
+                channel_id,
+                length,
+                ..
+            }) => (channel_id, [title, channel], Some(length)),
+            Item::MiniPlaylist(MiniPlaylistItem {
+                title,
+                channel,
+                channel_id,
+                ..
+            })
+            | Item::FullPlaylist(FullPlaylistItem {
+                title,
+                channel,
+                channel_id,
+                ..
+            }) => (channel_id, [title, channel], None),
+            Item::MiniChannel(MiniChannelItem { name, id, .. })
+            | Item::FullChannel(FullChannelItem { name, id, .. }) => (id, [name, name], None),
+            Item::CommunityPost(CommunityPostItem {
+                content,
+                channel,
+                channel_id,
+                ..
+            }) => (channel_id, [content, channel], None),
+            Item::SearchQuery(_)
+            | Item::SavedSearch(_)
+            | Item::Page(_)
+            | Item::LoadMore
+            | Item::Unknown(_) => return true,
+        };
+
+        if self.blocked_channels.iter().any(|id| id == channel_id) {
+            return false;
+        }
+
+        if texts.iter().any(|text| {
+            let text_lower = text.to_lowercase();
+            self.blocked_keywords
+                .iter()
+                .any(|keyword| !keyword.is_empty() && text_lower.contains(&keyword.to_lowercase()))
+                || self.regexes.iter().any(|regex| regex.is_match(text))
+        }) {
+            return false;
+        }
+
+        // feed items with unknown length are never hidden by length
+        if let Some(secs) = length.and_then(length_secs) {
+            // live streams are the only videos with a length of 0
+            if secs == 0 {
+                return !self.hide_live;
+            }
+            // the api does not tell if a video is a short, so they are guessed by length
+            if self.hide_shorts && secs <= self.shorts_max_length {
+                return false;
+            }
+            if secs < self.min_length {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// removes all items that should not be displayed
+    pub fn apply(&self, items: &mut Vec<Item>) {
+        items.retain(|item| self.allows(item))
+    }
+}
+
+/// parse length text like `1:02:03` back into seconds
+fn length_secs(text: &str) -> Option<u32> {
+    text.split(':')
+        .try_fold(0, |secs, part| Some(secs * 60 + part.parse::<u32>().ok()?))
+}
+
+// shorts can be up to 3 minutes long
+fn shorts_max_length_default() -> u32 {
+    180
+}
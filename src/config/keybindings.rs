@@ -3,18 +3,12 @@ use super::{
     WriteConfig,
 };
 use crate::global::{
-    structs::KeyAction,
-    traits::{ConfigTrait, EXTENSION},
+    structs::{ConfigIssue, KeyAction},
+    traits::ConfigTrait,
 };
 use crossterm::event::{KeyCode, KeyEvent};
-use home::home_dir;
 use serde::{Deserialize, Serialize};
-use std::{
-    collections::HashMap,
-    error::Error,
-    fs::{self, OpenOptions},
-    io::Write,
-};
+use std::{collections::HashMap, error::Error};
 use typemap::Key;
 
 // The outer HashMap<KeyCode, T> gets a value for whatever code is being pressed
@@ -122,66 +116,25 @@ impl Default for KeyBindingsConfigSerde {
 
 impl KeyBindingsConfigSerde {
     /// converts KeyBindingsConfigSerde into KeyBindingsConfig
-    pub fn into(self) -> Option<KeyBindingsConfig> {
+    pub fn into(self) -> Result<KeyBindingsConfig, ConfigIssue> {
         let mut out = HashMap::new();
 
         // simply loops over the serde hashmap and insert them into the new one
         for (keycodeserde, map) in self.0.into_iter() {
-            out.insert(keycodeserde.to_keycode()?, map);
+            out.insert(keycodeserde.try_keycode(&[])?, map);
         }
 
-        Some(KeyBindingsConfig(out))
+        Ok(KeyBindingsConfig(out))
     }
 }
 
 impl KeyBindingsConfig {
-    /// generates a new file if the original one is invalid
+    /// reports the invalid key if the file cannot be converted
     pub fn load(write: WriteConfig) -> Result<Self, Box<dyn Error>> {
         let serde = *KeyBindingsConfigSerde::load(write)?;
-        // check if it be converted from `KeyBindingsConfigSerde` to `KeyBindingsConfig`
-        let try_into = serde.into();
-
-        if let Some(keybindings) = try_into {
-            return Ok(keybindings);
-        }
-
-        let config_path = home_dir().unwrap().join(format!(
-            ".config/youtube-tui/{}.{}",
-            KeyBindingsConfigSerde::LABEL,
-            EXTENSION
-        ));
-
-        // if it cannot, back it up and regenerate it
-        let mut new_path = config_path.clone();
-        new_path.pop();
-        new_path.push(format!(
-            "{}-{}.{}",
-            KeyBindingsConfigSerde::LABEL,
-            chrono::offset::Local::now(),
-            EXTENSION
-        ));
-        fs::rename(&config_path, &new_path)?;
-
-        // here generates a default and write it to the file
-        let default = KeyBindingsConfigSerde::default();
-
-        let mut file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&config_path)?;
-
-        file.write_all(serde_yaml::to_string(&default)?.as_bytes())?;
-
-        match default.into() {
-            Some(keybindings) => Ok(keybindings),
-            // if the default still cannot be converted, panic and exit the program
-            None => panic!(
-                "the default for {}.{} is invalid",
-                KeyBindingsConfigSerde::LABEL,
-                EXTENSION
-            ),
-        }
+        Ok(serde
+            .into()
+            .map_err(|issue| issue.in_config(KeyBindingsConfigSerde::LABEL))?)
     }
 
     // gets the keyaction with the key
@@ -1,4 +1,5 @@
-use crate::global::traits::ConfigTrait;
+use super::CONFIG_VERSION;
+use crate::global::{functions::data_dir, traits::ConfigTrait};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use typemap::Key;
@@ -14,6 +15,8 @@ pub struct MainConfig {
     pub write_config: WriteConfig,
     #[serde(default = "max_watch_history_default")]
     pub max_watch_history: usize,
+    #[serde(default = "max_search_history_default")]
+    pub max_search_history: usize,
     #[serde(default = "allow_unicode_default")]
     pub allow_unicode: bool,
     #[serde(default = "message_bar_default_default")]
@@ -22,6 +25,9 @@ pub struct MainConfig {
     pub images: Images,
     #[serde(default = "refresh_after_modifying_search_filters_default")]
     pub refresh_after_modifying_search_filters: bool,
+    #[serde(default = "search_suggestions_default")]
+    // show suggestions under the search bar while typing
+    pub search_suggestions: bool,
     #[serde(default = "image_index_default")]
     // The image to download from the array of images provided by the invidious api
     // 0 is usually `maxres` and 3 (default) is good enough for normal uses without having huge files sizes
@@ -29,10 +35,30 @@ pub struct MainConfig {
     pub image_index: usize,
     #[serde(default = "provider_default")]
     pub provider: Provider,
+    #[serde(default = "region_default")]
+    // ISO 3166 code of the region to load the trending page from
+    pub region: String,
+    #[serde(default = "use_channel_feeds_default")]
+    // load subscriptions from channel feeds instead of the api, channels which feeds cannot be
+    // fetched falls back to using the api
+    pub use_channel_feeds: bool,
+    #[serde(default = "refresh_interval_default")]
+    // seconds between refreshing subscriptions in the background, 0 to disable
+    pub refresh_interval: u64,
+    #[serde(default = "new_video_hook_default")]
+    // shell command to run for every new video found by the background refresher
+    pub new_video_hook: String,
+    #[serde(default = "control_socket_default")]
+    // listen for commands from other programs on a unix socket
+    pub control_socket: bool,
     #[serde(default = "shell_default")]
     pub shell: String,
     #[serde(default = "default_env")]
     pub env: HashMap<String, String>,
+    #[serde(default)]
+    // version of the config files, used to migrate them after updates, files without it are from
+    // before migrations were added
+    pub config_version: u32,
 }
 
 impl Key for MainConfig {
@@ -46,16 +72,24 @@ impl Default for MainConfig {
             mouse_support: mouse_support_default(),
             invidious_instance: invidious_instance_default(),
             max_watch_history: max_watch_history_default(),
+            max_search_history: max_search_history_default(),
             allow_unicode: allow_unicode_default(),
             message_bar_default: message_bar_default_default(),
             images: images_default(),
             image_index: image_index_default(),
             refresh_after_modifying_search_filters: refresh_after_modifying_search_filters_default(
             ),
+            search_suggestions: search_suggestions_default(),
             provider: provider_default(),
+            region: region_default(),
+            use_channel_feeds: use_channel_feeds_default(),
+            refresh_interval: refresh_interval_default(),
+            new_video_hook: new_video_hook_default(),
+            control_socket: control_socket_default(),
             shell: shell_default(),
 
             env: default_env(),
+            config_version: CONFIG_VERSION,
         }
     }
 }
@@ -137,14 +171,42 @@ const fn refresh_after_modifying_search_filters_default() -> bool {
     true
 }
 
+const fn search_suggestions_default() -> bool {
+    true
+}
+
 const fn provider_default() -> Provider {
     Provider::YouTube
 }
 
+fn region_default() -> String {
+    String::from("US")
+}
+
+const fn use_channel_feeds_default() -> bool {
+    true
+}
+
+const fn refresh_interval_default() -> u64 {
+    0
+}
+
+fn new_video_hook_default() -> String {
+    String::new()
+}
+
+const fn control_socket_default() -> bool {
+    false
+}
+
 const fn max_watch_history_default() -> usize {
     50
 }
 
+const fn max_search_history_default() -> usize {
+    50
+}
+
 const fn mouse_support_default() -> bool {
     true
 }
@@ -168,7 +230,7 @@ fn default_env() -> HashMap<String, String> {
         ),
         (
             String::from("save-path"),
-            String::from("~/.local/share/youtube-tui/saved/"),
+            format!("{}/", data_dir().join("saved").display()),
         ),
     ])
 }
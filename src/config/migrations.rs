@@ -0,0 +1,238 @@
+use super::{MainConfig, WriteConfig};
+use crate::global::{
+    functions::{config_dir, profiles, profiles_dir, remove_key, rename_key, update_keys},
+    traits::{ConfigTrait, EXTENSION},
+};
+use serde_yaml::Value;
+use std::{collections::HashMap, error::Error, fs};
+
+/// a change to the config files between versions, applied to the text of the files so that
+/// comments are kept
+pub enum Migration {
+    /// renames the last part of a nested key
+    Rename {
+        file: &'static str,
+        key: &'static [&'static str],
+        to: &'static str,
+    },
+    Remove {
+        file: &'static str,
+        key: &'static [&'static str],
+    },
+    /// replaces text anywhere in the file, such as a default value that has changed
+    Replace {
+        file: &'static str,
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+impl Migration {
+    fn file(&self) -> &'static str {
+        match self {
+            Self::Rename { file, .. } | Self::Remove { file, .. } | Self::Replace { file, .. } => {
+                file
+            }
+        }
+    }
+
+    /// options which are not in the file are left alone
+    fn apply(&self, content: &str) -> String {
+        match self {
+            Self::Rename { key, to, .. } => rename_key(content, key, to),
+            Self::Remove { key, .. } => remove_key(content, key),
+            Self::Replace { from, to, .. } => Some(content.replace(from, to)),
+        }
+        .unwrap_or_else(|| content.to_string())
+    }
+}
+
+/// migrations to bring config files of version `i` to version `i + 1`, add new migrations to the
+/// end of the list
+pub const MIGRATIONS: &[&[Migration]] = &[
+    // 1: commands use the cache directory env instead of a hard coded path
+    &[
+        Migration::Replace {
+            file: "commands",
+            from: "'~/.cache/youtube-tui/info/${id}.json'",
+            to: "'${cache-dir}/info/${id}.json'",
+        },
+        Migration::Replace {
+            file: "commands",
+            from: "rm ~/.cache/youtube-tui/info/${id}.json",
+            to: "rm '${cache-dir}/info/${id}.json'",
+        },
+    ],
+];
+
+/// version of config files created by this version of the app
+pub const CONFIG_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// updates config files written by older versions, the version is stored as `config_version` in
+/// `main.yml`
+pub fn migrate_configs() -> Result<(), Box<dyn Error>> {
+    migrate(MIGRATIONS)
+}
+
+fn migrate(migrations: &[&[Migration]]) -> Result<(), Box<dyn Error>> {
+    let main_path = config_dir().join(format!("{}.{EXTENSION}", MainConfig::LABEL));
+    // new config files are already up to date, and invalid ones are reported when loaded
+    let Some(main) = fs::read_to_string(&main_path)
+        .ok()
+        .and_then(|content| serde_yaml::from_str::<Value>(&content).ok())
+    else {
+        return Ok(());
+    };
+
+    let version = main
+        .get("config_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+    let write = main
+        .get("write_config")
+        .and_then(|write| serde_yaml::from_value::<WriteConfig>(write.clone()).ok())
+        .unwrap_or(WriteConfig::Try);
+    if version >= migrations.len() || write == WriteConfig::Dont {
+        return Ok(());
+    }
+
+    let mut files = HashMap::new();
+    for migration in migrations[version..].iter().flat_map(|step| step.iter()) {
+        // files in profiles are migrated as well
+        let dirs = [config_dir()]
+            .into_iter()
+            .chain(profiles().into_iter().map(|name| profiles_dir().join(name)));
+        for path in dirs.map(|dir| dir.join(format!("{}.{EXTENSION}", migration.file()))) {
+            let Some(content) = files
+                .remove(&path)
+                .or_else(|| fs::read_to_string(&path).ok())
+            else {
+                continue;
+            };
+            files.insert(path, migration.apply(&content));
+        }
+    }
+
+    // the version is only updated if all files are written, so that failed migrations are tried
+    // again on the next launch
+    let result = || -> Result<(), Box<dyn Error>> {
+        for (path, content) in files {
+            if fs::read_to_string(&path).ok().as_ref() != Some(&content) {
+                fs::write(path, content)?;
+            }
+        }
+
+        // read again as it may have been migrated
+        let main_content = fs::read_to_string(&main_path)?;
+        let mut main = serde_yaml::from_str::<Value>(&main_content)?;
+        main.as_mapping_mut()
+            .ok_or("main.yml is not a mapping")?
+            .insert("config_version".into(), migrations.len().into());
+        let main_content = update_keys(&main_content, &main).ok_or("cannot edit main.yml")?;
+        fs::write(main_path, main_content)?;
+
+        Ok(())
+    }();
+
+    match write {
+        WriteConfig::Must => result,
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headless::lock_dirs;
+
+    const TEST_MIGRATIONS: &[&[Migration]] = &[
+        &[Migration::Rename {
+            file: "main",
+            key: &["env", "browser"],
+            to: "web-browser",
+        }],
+        &[
+            Migration::Remove {
+                file: "main",
+                key: &["old_option"],
+            },
+            Migration::Replace {
+                file: "appearance",
+                from: "Blue",
+                to: "LightBlue",
+            },
+        ],
+    ];
+
+    fn write(path: &str, content: &str) {
+        let path = config_dir().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn read(path: &str) -> String {
+        fs::read_to_string(config_dir().join(path)).unwrap()
+    }
+
+    #[test]
+    fn migrates_files_and_profiles() {
+        let _lock = lock_dirs();
+        let _ = fs::remove_dir_all(config_dir());
+
+        write(
+            "main.yml",
+            "# comment\nold_option: 1\nenv:\n  browser: firefox # keep\n",
+        );
+        write("appearance.yml", "outline: Blue\n");
+        write("profiles/laptop/main.yml", "env:\n  browser: chromium\n");
+        write("profiles/laptop/appearance.yml", "outline_hover: Blue\n");
+        migrate(TEST_MIGRATIONS).unwrap();
+
+        assert_eq!(
+            read("main.yml"),
+            "# comment\nenv:\n  web-browser: firefox # keep\nconfig_version: 2\n"
+        );
+        assert_eq!(read("appearance.yml"), "outline: LightBlue\n");
+        assert_eq!(
+            read("profiles/laptop/main.yml"),
+            "env:\n  web-browser: chromium\n"
+        );
+        assert_eq!(
+            read("profiles/laptop/appearance.yml"),
+            "outline_hover: LightBlue\n"
+        );
+
+        // migrations are only applied once
+        write("appearance.yml", "outline: Blue\n");
+        migrate(TEST_MIGRATIONS).unwrap();
+        assert_eq!(read("appearance.yml"), "outline: Blue\n");
+    }
+
+    #[test]
+    fn migrates_from_config_version() {
+        let _lock = lock_dirs();
+        let _ = fs::remove_dir_all(config_dir());
+
+        // only the second step is applied
+        write(
+            "main.yml",
+            "config_version: 1\nold_option: 1\nenv:\n  browser: firefox\n",
+        );
+        migrate(TEST_MIGRATIONS).unwrap();
+        assert_eq!(
+            read("main.yml"),
+            "config_version: 2\nenv:\n  browser: firefox\n"
+        );
+    }
+
+    #[test]
+    fn does_not_migrate_without_writing() {
+        let _lock = lock_dirs();
+        let _ = fs::remove_dir_all(config_dir());
+
+        let content = "write_config: Dont\nold_option: 1\n";
+        write("main.yml", content);
+        migrate(TEST_MIGRATIONS).unwrap();
+        assert_eq!(read("main.yml"), content);
+    }
+}
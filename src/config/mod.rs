@@ -13,5 +13,9 @@ mod commands;
 pub use commands::*;
 mod commandbindings;
 pub use commandbindings::*;
+mod filters;
+pub use filters::*;
+mod migrations;
+pub use migrations::*;
 
 pub mod serde;
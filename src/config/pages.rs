@@ -28,6 +28,8 @@ impl Key for MinDimentions {
 pub struct PagesConfig {
     #[serde(default = "main_menu_default")]
     pub main_menu: PageConfig,
+    #[serde(default = "trending_default")]
+    pub trending: PageConfig,
     #[serde(default = "search_default")]
     pub search: PageConfig,
     #[serde(default = "singleitem_default")]
@@ -48,6 +50,7 @@ impl Default for PagesConfig {
     fn default() -> Self {
         Self {
             main_menu: main_menu_default(),
+            trending: trending_default(),
             search: search_default(),
             singleitem: singleitem_default(),
             channeldisplay: channeldisplay_default(),
@@ -59,7 +62,7 @@ impl Default for PagesConfig {
 // Each page has its own minimum width and height, which is automatically determined by the items in the page
 // If it doesn't meet the minimum dimentions, a "protective screen" will be shown to prevent panicking
 /// Layout for one single page
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PageConfig {
     pub layout: Vec<PageRow>,
     pub message: String,
@@ -116,7 +119,7 @@ impl PageConfig {
 }
 
 /// CenteredRow will have its items centered, while NonCenteredRow will align to the left
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "type", content = "items")]
 pub enum PageRow {
     CenteredRow(Vec<PageItems>),
@@ -150,7 +153,7 @@ impl PageRow {
 // Seen https://docs.rs/tui-additions/latest/tui_additions/framework/trait.FrameworkItem.html
 // Each item has a minimum width and height for it to render without panicking
 /// All avaliable items for `PageConfig`
-#[derive(Clone, Copy, Serialize, Deserialize)]
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum PageItems {
     /// the search bar
     SearchBar,
@@ -162,8 +165,20 @@ pub enum PageItems {
     Trending,
     /// the popular button which loads the popular page
     Popular,
+    /// button which loads the default trending tab
+    TrendingNow,
+    /// button which loads the trending music tab
+    TrendingMusic,
+    /// button which loads the trending gaming tab
+    TrendingGaming,
+    /// button which loads the trending news tab
+    TrendingNews,
+    /// button which loads the trending movies tab
+    TrendingMovies,
     /// the history button which loads the watch history page
     History,
+    /// the searches button which loads the search history page
+    SearchHistory,
     /// the subscriptions button which loads the subscriptions page
     Subscriptions,
     /// the library button which loads the saved page
@@ -178,8 +193,16 @@ pub enum PageItems {
     ChannelMain,
     /// button which loads the channel videos page
     ChannelVideos,
+    /// button which loads the channel shorts page
+    ChannelShorts,
+    /// button which loads the channel live streams page
+    ChannelStreams,
     /// button which loads the channel playlists page
     ChannelPlaylists,
+    /// button which loads the channel community posts page
+    ChannelCommunity,
+    /// search bar for searching videos within the current channel
+    ChannelSearchBar,
 }
 
 impl PageItems {
@@ -189,7 +212,13 @@ impl PageItems {
             Self::SearchBar => Box::<SearchBar>::default(),
             Self::Popular => Box::new(PageButton::Popular),
             Self::Trending => Box::new(PageButton::Trending),
+            Self::TrendingNow => Box::new(PageButton::TrendingNow),
+            Self::TrendingMusic => Box::new(PageButton::TrendingMusic),
+            Self::TrendingGaming => Box::new(PageButton::TrendingGaming),
+            Self::TrendingNews => Box::new(PageButton::TrendingNews),
+            Self::TrendingMovies => Box::new(PageButton::TrendingMovies),
             Self::History => Box::new(PageButton::History),
+            Self::SearchHistory => Box::new(PageButton::SearchHistory),
             Self::Subscriptions => Box::new(PageButton::Subscriptions),
             Self::Library => Box::new(PageButton::Library),
             Self::MessageBar => Box::<MessageBar>::default(),
@@ -199,7 +228,11 @@ impl PageItems {
             Self::ChannelDisplay => Box::<ChannelDisplay>::default(),
             Self::ChannelMain => Box::new(PageButton::ChannelMain),
             Self::ChannelVideos => Box::new(PageButton::ChannelVideos),
+            Self::ChannelShorts => Box::new(PageButton::ChannelShorts),
+            Self::ChannelStreams => Box::new(PageButton::ChannelStreams),
             Self::ChannelPlaylists => Box::new(PageButton::ChannelPlaylists),
+            Self::ChannelCommunity => Box::new(PageButton::ChannelCommunity),
+            Self::ChannelSearchBar => Box::new(SearchBar::channel()),
         }
     }
 
@@ -208,12 +241,23 @@ impl PageItems {
             Self::Popular
             | Self::Trending
             | Self::History
+            | Self::SearchHistory
             | Self::Subscriptions
-            | Self::Library
+            | Self::Library => Constraint::Length(15),
+            // narrower so that all channel tabs fits in 80 columns
+            Self::TrendingNow
+            | Self::TrendingMusic
+            | Self::TrendingGaming
+            | Self::TrendingNews
+            | Self::TrendingMovies
             | Self::ChannelMain
             | Self::ChannelVideos
-            | Self::ChannelPlaylists => Constraint::Length(15),
+            | Self::ChannelShorts
+            | Self::ChannelStreams
+            | Self::ChannelPlaylists
+            | Self::ChannelCommunity => Constraint::Length(12),
             Self::SearchBar => Constraint::Min(16),
+            Self::ChannelSearchBar => Constraint::Length(30),
             Self::MessageBar => Constraint::Min(3),
             Self::ItemList | Self::SingleItemInfo | Self::ChannelDisplay => Constraint::Min(9),
             Self::SearchFilters => Constraint::Length(5),
@@ -224,14 +268,24 @@ impl PageItems {
         match self {
             Self::Popular
             | Self::History
+            | Self::SearchHistory
             | Self::Subscriptions
             | Self::Library
+            | Self::TrendingNow
+            | Self::TrendingMusic
+            | Self::TrendingGaming
+            | Self::TrendingNews
+            | Self::TrendingMovies
             | Self::ChannelMain
             | Self::ChannelVideos
+            | Self::ChannelShorts
+            | Self::ChannelStreams
             | Self::ChannelPlaylists
+            | Self::ChannelCommunity
             | Self::Trending
             | Self::MessageBar
             | Self::SearchBar
+            | Self::ChannelSearchBar
             | Self::SearchFilters => Constraint::Length(3),
             Self::ItemList | Self::SingleItemInfo | Self::ChannelDisplay => Constraint::Min(6),
         }
@@ -267,7 +321,13 @@ fn main_menu_default() -> PageConfig {
         layout: vec![
             PageRow::from_vec(vec![PageItems::SearchBar, PageItems::SearchFilters], false),
             PageRow::from_vec(
-                vec![PageItems::Popular, PageItems::Library, PageItems::History],
+                vec![
+                    PageItems::Popular,
+                    PageItems::Subscriptions,
+                    PageItems::Library,
+                    PageItems::History,
+                    PageItems::SearchHistory,
+                ],
                 true,
             ),
             PageRow::from_vec(vec![PageItems::ItemList], false),
@@ -277,6 +337,26 @@ fn main_menu_default() -> PageConfig {
     }
 }
 
+// the main menu with a row of trending tabs
+fn trending_default() -> PageConfig {
+    let mut config = main_menu_default();
+    config.layout.insert(
+        2,
+        PageRow::from_vec(
+            vec![
+                PageItems::TrendingNow,
+                PageItems::TrendingMusic,
+                PageItems::TrendingGaming,
+                PageItems::TrendingNews,
+                PageItems::TrendingMovies,
+            ],
+            true,
+        ),
+    );
+    config.message = String::from("Loading trending videos...");
+    config
+}
+
 fn search_default() -> PageConfig {
     PageConfig {
         layout: vec![
@@ -302,12 +382,22 @@ fn singleitem_default() -> PageConfig {
 fn channeldisplay_default() -> PageConfig {
     PageConfig {
         layout: vec![
-            PageRow::from_vec(vec![PageItems::SearchBar, PageItems::SearchFilters], false),
+            PageRow::from_vec(
+                vec![
+                    PageItems::SearchBar,
+                    PageItems::SearchFilters,
+                    PageItems::ChannelSearchBar,
+                ],
+                false,
+            ),
             PageRow::from_vec(
                 vec![
                     PageItems::ChannelMain,
                     PageItems::ChannelVideos,
+                    PageItems::ChannelShorts,
+                    PageItems::ChannelStreams,
                     PageItems::ChannelPlaylists,
+                    PageItems::ChannelCommunity,
                 ],
                 true,
             ),
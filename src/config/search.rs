@@ -1,6 +1,8 @@
 use crate::global::{structs::Message, traits::*};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 use typemap::Key;
+use url::form_urlencoded;
 
 // can be turned into URL Params for the search term with filters
 /// Search query & filters
@@ -34,12 +36,22 @@ impl ToString for Search {
         format!(
             "{}&page={}",
             vec![
-                format!("q={}", self.query),
+                format!(
+                    "q={}",
+                    form_urlencoded::byte_serialize(self.query.as_bytes()).collect::<String>()
+                ),
                 self.filters.sort.as_url_string(),
                 self.filters.date.as_url_string(),
                 self.filters.duration.as_url_string(),
                 self.filters.r#type.as_url_string(),
+                self.filters.features.as_url_string(),
+                self.filters.region.as_url_string(),
+                self.filters.language.as_url_string(),
             ]
+            .into_iter()
+            // unset filters are left out
+            .filter(|param| !param.is_empty())
+            .collect::<Vec<_>>()
             .join("&"),
             self.page
         )
@@ -47,39 +59,104 @@ impl ToString for Search {
 }
 
 /// Search filters, read comments for function docs
-#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SearchFilters {
     pub sort: SearchFilterSort,
     pub date: SearchFilterDate,
     pub duration: SearchFilterDuration,
     pub r#type: SearchFilterType,
+    // defaulted so that search configs from older versions can still be loaded
+    #[serde(default)]
+    pub features: SearchFilterFeatures,
+    #[serde(default)]
+    pub region: SearchFilterRegion,
+    #[serde(default)]
+    pub language: SearchFilterLanguage,
 }
 
+// position of each filter in the popup, same as the order in `SearchFilters::get_all()`
+const SORT_INDEX: usize = 0;
+const DATE_INDEX: usize = 1;
+const DURATION_INDEX: usize = 2;
+const TYPE_INDEX: usize = 3;
+const FEATURES_INDEX: usize = 4;
+const REGION_INDEX: usize = 5;
+const LANGUAGE_INDEX: usize = 6;
+const RESET_INDEX: usize = 7;
+
 // These functions are used in the search filter item
 // Think of the ordering of stuff in the popup menu
 impl SearchFilters {
     // This function returns [(Option name, [Options])]
     // Option name is a string like "sory by" and "type"
     // Options are the options that you can select like "relevance" and "upload date" for sorting
-    pub fn get_all() -> [(&'static str, Vec<&'static str>); 5] {
+    pub fn get_all() -> [(&'static str, Vec<&'static str>); 8] {
         [
             (SearchFilterSort::NAME, SearchFilterSort::ordering()),
             (SearchFilterDate::NAME, SearchFilterDate::ordering()),
             (SearchFilterDuration::NAME, SearchFilterDuration::ordering()),
             (SearchFilterType::NAME, SearchFilterType::ordering()),
+            (SearchFilterFeature::NAME, SearchFilterFeature::ordering()),
+            (SearchFilterRegion::NAME, SearchFilterRegion::ordering()),
+            (SearchFilterLanguage::NAME, SearchFilterLanguage::ordering()),
             ("Reset filters", vec!["Are you sure?"]),
         ]
     }
 
+    // multiple features can be selected at once, so they are toggled on select instead of being
+    // set when hovered
+    pub fn is_multi_select(index: usize) -> bool {
+        index == FEATURES_INDEX
+    }
+
+    // the options displayed in the right text list, selected features are marked with `[x]`
+    // and region or language codes that are not in the list are added after the listed ones
+    pub fn labels(&self, index: usize, options: &[&'static str]) -> Vec<String> {
+        let unlisted = match index {
+            FEATURES_INDEX => return self.feature_labels(),
+            REGION_INDEX => self.region.unlisted(),
+            LANGUAGE_INDEX => self.language.unlisted(),
+            _ => None,
+        };
+
+        options
+            .iter()
+            .copied()
+            .chain(unlisted)
+            .map(|option| option.to_string())
+            .collect()
+    }
+
+    fn feature_labels(&self) -> Vec<String> {
+        FEATURE_ORDERING
+            .iter()
+            .map(|feature| {
+                format!(
+                    "[{}] {}",
+                    if self.features.0.contains(feature) {
+                        'x'
+                    } else {
+                        ' '
+                    },
+                    feature.option_name()
+                )
+            })
+            .collect()
+    }
+
     // uses the index of the selected option name to get the index of the selected option
     // aka using the hover location of the left textlist to get the hover location of the right text list
-    pub fn get_selected(&self, index: usize) -> usize {
+    // returns `None` for multi select options, which has no single selected option
+    pub fn get_selected(&self, index: usize) -> Option<usize> {
         match index {
-            0 => self.sort.selected_index(),
-            1 => self.date.selected_index(),
-            2 => self.duration.selected_index(),
-            3 => self.r#type.selected_index(),
-            4 => 0,
+            SORT_INDEX => Some(self.sort.selected_index()),
+            DATE_INDEX => Some(self.date.selected_index()),
+            DURATION_INDEX => Some(self.duration.selected_index()),
+            TYPE_INDEX => Some(self.r#type.selected_index()),
+            FEATURES_INDEX => None,
+            REGION_INDEX => Some(self.region.selected_index()),
+            LANGUAGE_INDEX => Some(self.language.selected_index()),
+            RESET_INDEX => Some(0),
             _ => unreachable!("`{index}` is not a valid index"),
         }
     }
@@ -88,15 +165,23 @@ impl SearchFilters {
     //                           v left textlist   v right textlist
     pub fn set_index(&mut self, index_at: usize, set_index: usize, message: &mut Message) {
         match index_at {
-            0 => self.sort = SearchFilterSort::at_index(set_index),
-            1 => self.date = SearchFilterDate::at_index(set_index),
-            2 => self.duration = SearchFilterDuration::at_index(set_index),
-            3 => self.r#type = SearchFilterType::at_index(set_index),
-            4 => {
+            SORT_INDEX => self.sort = SearchFilterSort::at_index(set_index),
+            DATE_INDEX => self.date = SearchFilterDate::at_index(set_index),
+            DURATION_INDEX => self.duration = SearchFilterDuration::at_index(set_index),
+            TYPE_INDEX => self.r#type = SearchFilterType::at_index(set_index),
+            FEATURES_INDEX => self
+                .features
+                .toggle(SearchFilterFeature::at_index(set_index)),
+            // the index after the list is the unlisted code, which is kept as it is
+            REGION_INDEX if set_index >= SearchFilterRegion::ordering().len() => {}
+            REGION_INDEX => self.region = SearchFilterRegion::at_index(set_index),
+            LANGUAGE_INDEX if set_index >= SearchFilterLanguage::ordering().len() => {}
+            LANGUAGE_INDEX => self.language = SearchFilterLanguage::at_index(set_index),
+            RESET_INDEX => {
                 if set_index == 0 {
                     self.reset(message)
                 } else {
-                    unreachable!("index can only be `0` for option `4` (reset all)")
+                    unreachable!("index can only be `0` for option `{RESET_INDEX}` (reset all)")
                 }
             }
             _ => unreachable!("`{index_at}` is not a valid index"),
@@ -143,6 +228,71 @@ pub enum SearchFilterType {
     Playlist,
 }
 
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SearchFilterFeature {
+    Hd,
+    #[serde(rename = "4K")]
+    FourK,
+    Subtitles,
+    CreativeCommons,
+    #[serde(rename = "360")]
+    ThreeSixty,
+    Live,
+    Hdr,
+    Location,
+    Purchased,
+}
+
+/// features that search results must have, any number of them can be selected
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct SearchFilterFeatures(pub Vec<SearchFilterFeature>);
+
+impl SearchFilterFeatures {
+    /// selects the feature if it is not selected, and unselects it if it is
+    pub fn toggle(&mut self, feature: SearchFilterFeature) {
+        if self.0.contains(&feature) {
+            self.0.retain(|selected| *selected != feature);
+        } else {
+            self.0.push(feature);
+            // always in the same order as displayed
+            self.0.sort_by_key(|feature| feature.selected_index());
+        }
+    }
+}
+
+/// ISO 3166 code of the region to search in, `None` uses the default region of the instance
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct SearchFilterRegion(pub Option<String>);
+
+impl SearchFilterRegion {
+    /// the region code if it is set but not in the list of regions
+    pub fn unlisted(&self) -> Option<&str> {
+        unlisted_code(&self.0, &REGION_ORDERING)
+    }
+}
+
+/// ISO 639-1 code of the language of the results, `None` uses the default language of the instance
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct SearchFilterLanguage(pub Option<String>);
+
+impl SearchFilterLanguage {
+    /// the language code if it is set but not in the list of languages
+    pub fn unlisted(&self) -> Option<&str> {
+        unlisted_code(&self.0, &LANGUAGE_ORDERING)
+    }
+}
+
+fn unlisted_code<'a>(code: &'a Option<String>, listed: &[&str]) -> Option<&'a str> {
+    code.as_deref().filter(|code| {
+        !listed
+            .iter()
+            .any(|listed| listed.eq_ignore_ascii_case(code))
+    })
+}
+
 impl Default for SearchFilterSort {
     fn default() -> Self {
         Self::Relevance
@@ -213,6 +363,51 @@ impl ToString for SearchFilterType {
     }
 }
 
+impl Display for SearchFilterFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Hd => "hd",
+            Self::FourK => "4k",
+            Self::Subtitles => "subtitles",
+            Self::CreativeCommons => "creative_commons",
+            Self::ThreeSixty => "360",
+            Self::Live => "live",
+            Self::Hdr => "hdr",
+            Self::Location => "location",
+            Self::Purchased => "purchased",
+        })
+    }
+}
+
+impl Display for SearchFilterFeatures {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("none");
+        }
+
+        f.write_str(
+            &self
+                .0
+                .iter()
+                .map(|feature| feature.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+}
+
+impl Display for SearchFilterRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0.as_deref().unwrap_or("none"))
+    }
+}
+
+impl Display for SearchFilterLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0.as_deref().unwrap_or("none"))
+    }
+}
+
 impl AsUrlString for SearchFilterSort {
     const TAG: &'static str = "sort";
 }
@@ -229,6 +424,42 @@ impl AsUrlString for SearchFilterType {
     const TAG: &'static str = "type";
 }
 
+impl AsUrlString for SearchFilterFeatures {
+    const TAG: &'static str = "features";
+
+    // left out when no features are selected
+    fn as_url_string(&self) -> String {
+        if self.0.is_empty() {
+            return String::new();
+        }
+        format!("{}={self}", Self::TAG)
+    }
+}
+
+impl AsUrlString for SearchFilterRegion {
+    const TAG: &'static str = "region";
+
+    // left out when no region is selected
+    fn as_url_string(&self) -> String {
+        match &self.0 {
+            Some(region) => format!("{}={region}", Self::TAG),
+            None => String::new(),
+        }
+    }
+}
+
+impl AsUrlString for SearchFilterLanguage {
+    const TAG: &'static str = "hl";
+
+    // left out when no language is selected
+    fn as_url_string(&self) -> String {
+        match &self.0 {
+            Some(language) => format!("{}={language}", Self::TAG),
+            None => String::new(),
+        }
+    }
+}
+
 const SORT_ORDERING: [SearchFilterSort; 4] = [
     SearchFilterSort::Relevance,
     SearchFilterSort::Rating,
@@ -255,6 +486,35 @@ const TYPE_ORDERING: [SearchFilterType; 4] = [
     SearchFilterType::Channel,
     SearchFilterType::Playlist,
 ];
+const FEATURE_ORDERING: [SearchFilterFeature; 9] = [
+    SearchFilterFeature::Hd,
+    SearchFilterFeature::FourK,
+    SearchFilterFeature::Subtitles,
+    SearchFilterFeature::CreativeCommons,
+    SearchFilterFeature::ThreeSixty,
+    SearchFilterFeature::Live,
+    SearchFilterFeature::Hdr,
+    SearchFilterFeature::Location,
+    SearchFilterFeature::Purchased,
+];
+// regions supported by YouTube
+const REGION_ORDERING: [&str; 109] = [
+    "AE", "AR", "AT", "AU", "AZ", "BA", "BD", "BE", "BG", "BH", "BO", "BR", "BY", "CA", "CH", "CL",
+    "CO", "CR", "CY", "CZ", "DE", "DK", "DO", "DZ", "EC", "EE", "EG", "ES", "FI", "FR", "GB", "GE",
+    "GH", "GR", "GT", "HK", "HN", "HR", "HU", "ID", "IE", "IL", "IN", "IQ", "IS", "IT", "JM", "JO",
+    "JP", "KE", "KH", "KR", "KW", "KZ", "LA", "LB", "LI", "LK", "LT", "LU", "LV", "LY", "MA", "ME",
+    "MK", "MT", "MX", "MY", "NG", "NI", "NL", "NO", "NP", "NZ", "OM", "PA", "PE", "PG", "PH", "PK",
+    "PL", "PR", "PT", "PY", "QA", "RO", "RS", "RU", "SA", "SE", "SG", "SI", "SK", "SN", "SV", "TH",
+    "TN", "TR", "TW", "TZ", "UA", "UG", "US", "UY", "VE", "VN", "YE", "ZA", "ZW",
+];
+// languages supported by YouTube
+const LANGUAGE_ORDERING: [&str; 73] = [
+    "af", "am", "ar", "as", "az", "be", "bg", "bn", "bs", "ca", "cs", "da", "de", "el", "en", "es",
+    "et", "eu", "fa", "fi", "fr", "gl", "gu", "he", "hi", "hr", "hu", "hy", "id", "is", "it", "ja",
+    "ka", "kk", "km", "kn", "ko", "ky", "lo", "lt", "lv", "mk", "ml", "mn", "mr", "ms", "my", "ne",
+    "nl", "no", "or", "pa", "pl", "pt", "ro", "ru", "si", "sk", "sl", "sq", "sr", "sv", "sw", "ta",
+    "te", "th", "tr", "uk", "ur", "uz", "vi", "zh", "zu",
+];
 
 impl SearchFilterItem for SearchFilterSort {
     const NAME: &'static str = "Sort by";
@@ -391,3 +651,128 @@ impl SearchFilterItem for SearchFilterType {
         TYPE_ORDERING[index]
     }
 }
+
+impl SearchFilterItem for SearchFilterFeature {
+    const NAME: &'static str = "Features";
+
+    fn option_name(&self) -> &'static str {
+        match self {
+            Self::Hd => "HD",
+            Self::FourK => "4K",
+            Self::Subtitles => "Subtitles/CC",
+            Self::CreativeCommons => "Creative Commons",
+            Self::ThreeSixty => "360 degrees",
+            Self::Live => "Live",
+            Self::Hdr => "HDR",
+            Self::Location => "Location",
+            Self::Purchased => "Purchased",
+        }
+    }
+
+    fn ordering() -> Vec<&'static str> {
+        FEATURE_ORDERING
+            .iter()
+            .map(|item| item.option_name())
+            .collect::<Vec<_>>()
+    }
+
+    fn selected_index(&self) -> usize {
+        match self {
+            Self::Hd => 0,
+            Self::FourK => 1,
+            Self::Subtitles => 2,
+            Self::CreativeCommons => 3,
+            Self::ThreeSixty => 4,
+            Self::Live => 5,
+            Self::Hdr => 6,
+            Self::Location => 7,
+            Self::Purchased => 8,
+        }
+    }
+
+    fn at_index(index: usize) -> Self {
+        FEATURE_ORDERING[index]
+    }
+}
+
+impl SearchFilterItem for SearchFilterRegion {
+    const NAME: &'static str = "Region";
+
+    fn option_name(&self) -> &'static str {
+        match &self.0 {
+            Some(region) => REGION_ORDERING
+                .iter()
+                .find(|code| code.eq_ignore_ascii_case(region))
+                .unwrap_or(&"Other region"),
+            None => "Any region",
+        }
+    }
+
+    fn ordering() -> Vec<&'static str> {
+        std::iter::once("Any region")
+            .chain(REGION_ORDERING.iter().copied())
+            .collect::<Vec<_>>()
+    }
+
+    // regions not in the list are displayed after the listed ones
+    fn selected_index(&self) -> usize {
+        match &self.0 {
+            Some(region) => {
+                REGION_ORDERING
+                    .iter()
+                    .position(|code| code.eq_ignore_ascii_case(region))
+                    .unwrap_or(REGION_ORDERING.len())
+                    + 1
+            }
+            None => 0,
+        }
+    }
+
+    fn at_index(index: usize) -> Self {
+        match index {
+            0 => Self(None),
+            _ => Self(Some(REGION_ORDERING[index - 1].to_string())),
+        }
+    }
+}
+
+impl SearchFilterItem for SearchFilterLanguage {
+    const NAME: &'static str = "Language";
+
+    fn option_name(&self) -> &'static str {
+        match &self.0 {
+            Some(language) => LANGUAGE_ORDERING
+                .iter()
+                .find(|code| code.eq_ignore_ascii_case(language))
+                .unwrap_or(&"Other language"),
+            None => "Any language",
+        }
+    }
+
+    fn ordering() -> Vec<&'static str> {
+        std::iter::once("Any language")
+            .chain(LANGUAGE_ORDERING.iter().copied())
+            .collect::<Vec<_>>()
+    }
+
+    // languages not in the list are displayed after the listed ones
+    fn selected_index(&self) -> usize {
+        match &self.0 {
+            Some(language) => {
+                LANGUAGE_ORDERING
+                    .iter()
+                    .position(|code| code.eq_ignore_ascii_case(language))
+                    .unwrap_or(LANGUAGE_ORDERING.len())
+                    + 1
+            }
+            None => 0,
+        }
+    }
+
+    fn at_index(index: usize) -> Self {
+        match index {
+            0 => Self(None),
+            _ => Self(Some(LANGUAGE_ORDERING[index - 1].to_string())),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! Structs that impl `Into<T>` because `T` does not impl Serde but is used in config files
+use crate::global::structs::ConfigIssue;
 use crossterm::event::KeyCode;
 use serde::{Deserialize, Serialize};
 use tui::{style::Color, widgets::BorderType};
@@ -186,18 +187,24 @@ impl KeyCodeSerde {
             Self::KeyVariants(KeyVariantsSerde::Menu) => Some(KeyCode::Menu),
             Self::KeyVariants(KeyVariantsSerde::KeypadBegin) => Some(KeyCode::KeypadBegin),
             Self::Char(c) => Some(KeyCode::Char(c)),
-            Self::F(s) => {
-                if s[0..1].to_uppercase().as_str() != "F" {
-                    return None;
-                }
-
-                let parsed = s[1..].parse::<u8>();
-
-                match parsed {
-                    Ok(f) => Some(KeyCode::F(f)),
-                    Err(_) => None,
-                }
-            }
+            Self::F(s) => s
+                .strip_prefix(['F', 'f'])
+                .and_then(|f| f.parse::<u8>().ok())
+                .map(KeyCode::F),
         }
     }
+
+    /// same as `to_keycode()`, but reports the invalid key under `parent`
+    pub fn try_keycode(self, parent: &[&str]) -> Result<KeyCode, ConfigIssue> {
+        let text = match &self {
+            Self::F(s) => s.clone(),
+            _ => String::new(),
+        };
+        self.to_keycode().ok_or_else(|| {
+            ConfigIssue::new(
+                &[parent, &[text.as_str()]].concat(),
+                format!("`{text}` is not a valid key"),
+            )
+        })
+    }
 }
@@ -1,24 +1,40 @@
-use home::home_dir;
 use std::{error::Error, fs};
 use tui_additions::framework::Framework;
 
-use crate::global::{structs::WatchHistory, traits::Collection};
+#[cfg(unix)]
+use crate::global::functions::stop_control_socket;
+use crate::global::{
+    functions::cache_dir,
+    structs::{Subscriptions, WatchHistory},
+    traits::Collection,
+};
 
 /// function to run when the app ends
 // exit tasks:
-//  - move thumbnails of videos in watch history to `~/.local/share/youtube-tui/watch_history/thumbnails`
-//  - remove `~/.cache`
+//  - move thumbnails of videos in watch history and subscribed channels to `~/.local/share/youtube-tui/thumbnails`
+//  - remove the cache directory (`~/.cache/youtube-tui/`)
 pub fn exit(framework: &Framework) -> Result<(), Box<dyn Error>> {
     if let Some(history) = framework.data.global.get::<WatchHistory>() {
         history.exit_move()
     }
 
-    let home_dir = home_dir().unwrap();
-    let cache_path = home_dir.join(".cache/youtube-tui/");
+    if let Some(subscriptions) = framework.data.global.get::<Subscriptions>() {
+        subscriptions.exit_move()
+    }
+
+    #[cfg(unix)]
+    stop_control_socket(framework)?;
 
-    if cache_path.exists() {
-        fs::remove_dir_all(cache_path)?;
+    // only the directories created by `init()` are removed, in case the cache dir is set to a
+    // directory used by something else
+    for dir in ["info", "thumbnails"] {
+        let path = cache_dir().join(dir);
+        if path.exists() {
+            fs::remove_dir_all(path)?;
+        }
     }
+    // fails if the directory is not empty
+    let _ = fs::remove_dir(cache_dir());
 
     Ok(())
 }
@@ -0,0 +1,350 @@
+use crate::global::structs::{Errors, Item, MiniVideoItem};
+use chrono::DateTime;
+use futures::future::join_all;
+use invidious::reqwest::blocking::Client;
+use std::{cmp::Reverse, error::Error, time::Duration};
+
+use super::{date_text, runtime, viewcount_text};
+
+/// feeds that take longer than this are loaded from the api instead, so that a stalled feed does
+/// not freeze the page
+const FEED_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// a video from a channel feed, with the time it is published for sorting
+#[derive(Clone)]
+pub struct FeedEntry {
+    pub published: u64,
+    pub item: Item,
+}
+
+impl FeedEntry {
+    /// returns the id of the channel which uploaded the video
+    pub fn channel_id(&self) -> Option<&str> {
+        match &self.item {
+            Item::MiniVideo(MiniVideoItem { channel_id, .. }) if !channel_id.is_empty() => {
+                Some(channel_id)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// returns the url of the atom feed of a channel, `base` is either `https://www.youtube.com`
+/// or an Invidious instance
+pub fn feed_url(base: &str, channel_id: &str) -> String {
+    format!("{base}/feeds/videos.xml?channel_id={channel_id}")
+}
+
+/// parse a YouTube/Invidious channel atom feed, entries without a video id are skipped
+pub fn parse_feed(xml: &str) -> Result<Vec<FeedEntry>, Box<dyn Error>> {
+    if tag_content(xml, "feed").is_none() {
+        return Err(Errors::StrError("not a valid channel feed").into());
+    }
+
+    Ok(blocks(xml, "entry")
+        .into_iter()
+        .filter_map(|entry| {
+            let id = tag_content(entry, "yt:videoId")?.trim().to_string();
+            let published = tag_content(entry, "published")
+                .and_then(|published| DateTime::parse_from_rfc3339(published.trim()).ok())
+                .map(|published| published.timestamp().max(0) as u64)
+                .unwrap_or_default();
+            let author = tag_content(entry, "author").unwrap_or_default();
+
+            Some(FeedEntry {
+                published,
+                item: Item::MiniVideo(MiniVideoItem {
+                    title: unescape(tag_content(entry, "title").unwrap_or_default()),
+                    thumbnail_url: tag_attr(entry, "media:thumbnail", "url")
+                        .map(unescape)
+                        .unwrap_or_else(|| format!("https://i.ytimg.com/vi/{id}/hqdefault.jpg")),
+                    // feeds do not include the length of videos
+                    length: String::from("unknown"),
+                    views: tag_attr(entry, "media:statistics", "views")
+                        .and_then(|views| views.parse().ok())
+                        .map(viewcount_text),
+                    channel: unescape(tag_content(author, "name").unwrap_or_default()),
+                    channel_id: tag_content(entry, "yt:channelId")
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string(),
+                    published: if published == 0 {
+                        None
+                    } else {
+                        Some(date_text(published))
+                    },
+                    description: tag_content(entry, "media:description").map(unescape),
+                    id,
+                }),
+            })
+        })
+        .collect())
+}
+
+/// fetch and parse feeds concurrently, results are in the same order as the urls
+pub fn fetch_feeds(urls: Vec<String>) -> Vec<Result<Vec<FeedEntry>, String>> {
+    fetch_feeds_within(urls, FEED_TIMEOUT)
+}
+
+fn fetch_feeds_within(urls: Vec<String>, timeout: Duration) -> Vec<Result<Vec<FeedEntry>, String>> {
+    let client = match reqwest::Client::builder().timeout(timeout).build() {
+        Ok(client) => client,
+        Err(e) => return vec![Err(e.to_string()); urls.len()],
+    };
+
+    runtime()
+        .block_on(join_all(
+            urls.into_iter().map(|url| fetch_feed(&client, url)),
+        ))
+        .into_iter()
+        .map(|res| res.map_err(|e| e.to_string()))
+        .collect()
+}
+
+async fn fetch_feed(
+    client: &reqwest::Client,
+    url: String,
+) -> Result<Vec<FeedEntry>, Box<dyn Error>> {
+    let response = client.get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(Errors::StringError(format!("Feed returned {}", response.status())).into());
+    }
+
+    parse_feed(&response.text().await?)
+}
+
+/// returns the latest videos of all channels with the time they are published, newest first
+// channel feeds are used if `use_feeds` is true, channels which feeds cannot be fetched falls back
+// to the api
+pub fn subscriptions_feed(
+    channel_ids: &[String],
+    client: &Client,
+    base: &str,
+    use_feeds: bool,
+    image_index: usize,
+) -> Result<Vec<FeedEntry>, Box<dyn Error>> {
+    let feeds = if use_feeds {
+        fetch_feeds(channel_ids.iter().map(|id| feed_url(base, id)).collect())
+    } else {
+        vec![Err(String::new()); channel_ids.len()]
+    };
+
+    let mut entries = Vec::new();
+    let mut last_error = None;
+
+    for (id, feed) in channel_ids.iter().zip(feeds) {
+        match feed {
+            Ok(feed) => entries.extend(feed),
+            Err(_) => match client.channel_videos(id, None) {
+                Ok(videos) => entries.extend(videos.videos.into_iter().map(|video| FeedEntry {
+                    published: video.published,
+                    item: Item::from_channel_video(video, image_index),
+                })),
+                Err(e) => last_error = Some(e),
+            },
+        }
+    }
+
+    // only fails if nothing can be loaded at all
+    if let Some(e) = last_error {
+        if entries.is_empty() {
+            return Err(e);
+        }
+    }
+
+    entries.sort_by_key(|entry| Reverse(entry.published));
+    Ok(entries)
+}
+
+/// returns the index of the first opening `<tag>` (or `<tag attr="...">`)
+fn find_open(xml: &str, tag: &str) -> Option<usize> {
+    let pattern = format!("<{tag}");
+    let mut from = 0;
+
+    loop {
+        let index = xml[from..].find(&pattern)? + from;
+        let after = index + pattern.len();
+        match xml[after..].chars().next()? {
+            '>' | '/' | ' ' | '\t' | '\r' | '\n' => return Some(index),
+            _ => from = after,
+        }
+    }
+}
+
+/// returns the content between the first `<tag>` and `</tag>`
+fn tag_content<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let open = find_open(xml, tag)?;
+    let start = xml[open..].find('>')? + open + 1;
+    let end = xml[start..].find(&format!("</{tag}>"))? + start;
+    Some(&xml[start..end])
+}
+
+/// returns the value of an attribute in the first `<tag>`
+fn tag_attr<'a>(xml: &'a str, tag: &str, attr: &str) -> Option<&'a str> {
+    let open = find_open(xml, tag)?;
+    let tag = &xml[open..xml[open..].find('>')? + open];
+    let pattern = format!(" {attr}=\"");
+    let start = tag.find(&pattern)? + pattern.len();
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+/// returns the contents of all `<tag>`s
+fn blocks<'a>(mut xml: &'a str, tag: &str) -> Vec<&'a str> {
+    let mut out = Vec::new();
+    let close = format!("</{tag}>");
+
+    while let Some(content) = tag_content(xml, tag) {
+        out.push(content);
+        let consumed = content.as_ptr() as usize - xml.as_ptr() as usize + content.len();
+        xml = &xml[consumed + close.len()..];
+    }
+
+    out
+}
+
+/// decodes the text of an element, entities (including numeric ones such as `&#39;` and
+/// `&#x27;`) are replaced, and the content of CDATA sections is kept as it is
+fn unescape(s: &str) -> String {
+    let mut out = String::new();
+    let mut rest = s.trim();
+
+    loop {
+        let next = rest.find(['&', '<']).unwrap_or(rest.len());
+        out.push_str(&rest[..next]);
+        rest = &rest[next..];
+
+        if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").unwrap_or(cdata.len());
+            out.push_str(&cdata[..end]);
+            rest = cdata.get(end + 3..).unwrap_or_default();
+        } else if let Some((c, len)) = entity(rest) {
+            out.push(c);
+            rest = &rest[len..];
+        } else if let Some(c) = rest.chars().next() {
+            // not an entity, such as a lone `&`
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        } else {
+            break;
+        }
+    }
+
+    out.trim().to_string()
+}
+
+/// the character of an entity at the start of `s`, and the length of the entity
+fn entity(s: &str) -> Option<(char, usize)> {
+    let end = s.find(';')?;
+    let c = match s.strip_prefix('&')?.get(..end - 1)? {
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "amp" => '&',
+        name => {
+            let code = match name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+
+    Some((c, end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headless::serve;
+    use std::{fs, net::TcpListener, thread, time::Instant};
+
+    #[test]
+    fn unescapes_entities() {
+        assert_eq!(
+            unescape(" Rick&#39;s &#x2764;&#xFE0F; &amp;amp; &lt;3 "),
+            "Rick's ❤️ &amp; <3"
+        );
+        // invalid entities are kept as they are
+        assert_eq!(unescape("a & b &#xZZ; &nope; &"), "a & b &#xZZ; &nope; &");
+        assert_eq!(
+            unescape("<![CDATA[<b> &amp;]]> &amp; <![CDATA[x]]>"),
+            "<b> &amp; & x"
+        );
+    }
+
+    #[test]
+    fn parses_feed() {
+        let xml = fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/feed.xml"
+        ))
+        .unwrap();
+        let entries = parse_feed(&xml).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let videos = entries
+            .iter()
+            .map(|entry| match &entry.item {
+                Item::MiniVideo(video) => video,
+                _ => panic!("feed entries should be videos"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(entries[0].published, 1256453853);
+        assert_eq!(entries[0].channel_id(), Some("UCuAXFkgsw1L7xaCfnd5JJOw"));
+        assert_eq!(videos[0].id, "dQw4w9WgXcQ");
+        assert_eq!(
+            videos[0].title,
+            "Rick Astley - Never Gonna Give You Up (Official Music Video)"
+        );
+        assert_eq!(videos[0].channel, "Rick Astley");
+        assert_eq!(videos[0].published.as_deref(), Some("25/10/2009"));
+        assert_eq!(
+            videos[0].thumbnail_url,
+            "https://i1.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg"
+        );
+        assert!(videos[0]
+            .description
+            .as_ref()
+            .unwrap()
+            .contains("listen to Rick's ‘Never’ playlist ❤️"));
+
+        assert_eq!(entries[1].published, 1688144406);
+        assert_eq!(
+            videos[1].title,
+            "Rick Astley & Friends – Together Forever (Live) <2023>"
+        );
+        assert_eq!(
+            videos[1].thumbnail_url,
+            "https://i4.ytimg.com/vi/yPYZpwSpKmA/hqdefault.jpg?sqp=-oaymwEjCPYBEIoBSFryq4qpAxUIARUAAAAAGAElAADIQj0AgKJDeAE=&rs=AOn4CLA"
+        );
+        assert_eq!(
+            videos[1].description.as_deref(),
+            Some("Live at <Glastonbury> & the O2 – tickets: https://rickastley.co.uk/?ref=yt&amp;src=feed")
+        );
+    }
+
+    #[test]
+    fn stalled_feeds_time_out() {
+        // accepts connections but never replies
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let stalled = format!("http://{}/feed", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let _streams = listener.incoming().flatten().collect::<Vec<_>>();
+        });
+        let xml = fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/feed.xml"
+        ))
+        .unwrap();
+        let working = serve(xml);
+
+        let start = Instant::now();
+        let feeds = fetch_feeds_within(vec![stalled, working], Duration::from_millis(500));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(feeds[0].is_err());
+        assert_eq!(feeds[1].as_ref().unwrap().len(), 2);
+    }
+}
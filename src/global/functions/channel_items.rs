@@ -0,0 +1,221 @@
+use crate::global::structs::{ChannelSort, Errors, Item};
+use invidious::{
+    reqwest::blocking::Client,
+    structs::hidden::{ChannelVideo, SearchItem},
+};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::error::Error;
+use url::form_urlencoded;
+
+/// a post in the community tab of a channel, as returned by the invidious api
+#[derive(Deserialize)]
+pub struct CommunityPost {
+    #[serde(rename = "commentId")]
+    pub id: String,
+    #[serde(default)]
+    pub author: String,
+    #[serde(rename = "authorId", default)]
+    pub author_id: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(rename = "likeCount", default)]
+    pub likes: u64,
+    #[serde(default)]
+    pub published: u64,
+    #[serde(rename = "publishedText", default)]
+    pub published_text: String,
+    #[serde(default)]
+    pub attachment: Option<Value>,
+}
+
+/// the channel tabs that contains videos
+#[derive(Clone, Copy)]
+pub enum VideosTab {
+    Videos,
+    Shorts,
+    Streams,
+}
+
+impl VideosTab {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Videos => "videos",
+            Self::Shorts => "shorts",
+            Self::Streams => "streams",
+        }
+    }
+}
+
+/// gets a channel tab as a json object
+fn get_tab(url: String) -> Result<Map<String, Value>, Box<dyn Error>> {
+    let mut object = match serde_json::from_str::<Value>(&reqwest::blocking::get(url)?.text()?)? {
+        // older instances returns an array of videos
+        Value::Array(videos) => Map::from_iter([(String::from("videos"), Value::Array(videos))]),
+        Value::Object(object) => object,
+        _ => return Err(Errors::StrError("unexpected response from channel tab").into()),
+    };
+
+    if let Some(Value::String(error)) = object.remove("error") {
+        return Err(Errors::StringError(error).into());
+    }
+
+    Ok(object)
+}
+
+/// takes out the continuation token for the next batch
+fn take_continuation(object: &mut Map<String, Value>) -> Option<String> {
+    match object.remove("continuation") {
+        Some(Value::String(continuation)) => Some(continuation),
+        _ => None,
+    }
+}
+
+/// appends the continuation token to the url
+fn with_continuation(mut url: String, continuation: Option<&str>) -> String {
+    if let Some(continuation) = continuation {
+        url.push(if url.contains('?') { '&' } else { '?' });
+        url.push_str(
+            &form_urlencoded::Serializer::new(String::new())
+                .append_pair("continuation", continuation)
+                .finish(),
+        );
+    }
+    url
+}
+
+/// fetch a batch of videos, shorts or streams from a channel, returns the videos and the
+/// continuation token for the next batch (if any)
+// the response is parsed manually because newer instances returns `{ videos, continuation }`
+// instead of an array of videos
+pub fn channel_videos(
+    client: &Client,
+    id: &str,
+    tab: VideosTab,
+    sort: ChannelSort,
+    continuation: Option<&str>,
+    image_index: usize,
+) -> Result<(Vec<Item>, Option<String>), Box<dyn Error>> {
+    let mut object = get_tab(with_continuation(
+        format!(
+            "{}/api/v1/channels/{id}/{}?sort_by={}",
+            client.server,
+            tab.as_str(),
+            sort.as_str()
+        ),
+        continuation,
+    ))?;
+
+    let continuation = take_continuation(&mut object);
+    let videos = match object.remove("videos") {
+        Some(Value::Array(videos)) => videos,
+        _ => Vec::new(),
+    };
+
+    Ok((
+        videos
+            .into_iter()
+            .map(|video| {
+                Ok(Item::from_channel_video(
+                    serde_json::from_value::<ChannelVideo>(video)?,
+                    image_index,
+                ))
+            })
+            .collect::<Result<_, serde_json::Error>>()?,
+        continuation,
+    ))
+}
+
+/// fetch a batch of playlists from a channel, returns the playlists and the continuation token for
+/// the next batch (if any), playlists can only be sorted by newest or oldest
+pub fn channel_playlists(
+    client: &Client,
+    id: &str,
+    sort: ChannelSort,
+    continuation: Option<&str>,
+) -> Result<(Vec<Item>, Option<String>), Box<dyn Error>> {
+    let mut params = form_urlencoded::Serializer::new(String::new());
+    params.append_pair("sort_by", sort.as_str());
+    if let Some(continuation) = continuation {
+        params.append_pair("continuation", continuation);
+    }
+    let playlists = client.channel_playlists(id, Some(&params.finish()))?;
+
+    Ok((
+        playlists
+            .playlists
+            .into_iter()
+            .map(Item::from_channel_playlist)
+            .collect(),
+        playlists.continuation,
+    ))
+}
+
+/// fetch a batch of community posts from a channel, returns the posts and the continuation token
+/// for the next batch (if any)
+pub fn channel_community(
+    client: &Client,
+    id: &str,
+    continuation: Option<&str>,
+) -> Result<(Vec<Item>, Option<String>), Box<dyn Error>> {
+    let mut object = get_tab(with_continuation(
+        format!("{}/api/v1/channels/{id}/community", client.server),
+        continuation,
+    ))?;
+
+    let continuation = take_continuation(&mut object);
+    let posts = match object.remove("comments") {
+        Some(Value::Array(posts)) => posts,
+        _ => Vec::new(),
+    };
+
+    Ok((
+        posts
+            .into_iter()
+            .map(|post| {
+                Ok(Item::from_community_post(serde_json::from_value::<
+                    CommunityPost,
+                >(post)?))
+            })
+            .collect::<Result<_, serde_json::Error>>()?,
+        continuation,
+    ))
+}
+
+/// fetch a page of videos in a channel matching the query, returns the videos and the next page
+/// number as the continuation (if the page is not empty)
+pub fn channel_search(
+    client: &Client,
+    id: &str,
+    query: &str,
+    continuation: Option<&str>,
+    image_index: usize,
+) -> Result<(Vec<Item>, Option<String>), Box<dyn Error>> {
+    let page = continuation.unwrap_or("1");
+    let search = client.channel_search(
+        id,
+        Some(
+            &form_urlencoded::Serializer::new(String::new())
+                .append_pair("q", query)
+                .append_pair("page", page)
+                .finish(),
+        ),
+    )?;
+
+    let next = if search.items.is_empty() {
+        None
+    } else {
+        Some((page.parse::<u32>().unwrap_or(1) + 1).to_string())
+    };
+
+    Ok((
+        search
+            .items
+            .into_iter()
+            // only videos are shown in the channel search page
+            .filter(|item| matches!(item, SearchItem::Video { .. }))
+            .map(|item| Item::from_search_item(item, image_index))
+            .collect(),
+        next,
+    ))
+}
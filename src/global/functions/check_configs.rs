@@ -0,0 +1,53 @@
+use crate::{
+    config::*,
+    global::{structs::ConfigIssue, traits::ConfigTrait},
+};
+
+/// reads every config file without modifying them, returns all problems found
+pub fn check_configs() -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+    let mut check = |label: &str, res: Result<(), Box<dyn std::error::Error>>| {
+        if let Err(e) = res {
+            issues.push(ConfigIssue::from_error(label, e));
+        }
+    };
+
+    check(
+        MainConfig::LABEL,
+        MainConfig::load(WriteConfig::Dont).map(|_| ()),
+    );
+    check(
+        CommandsConfigSerde::LABEL,
+        CommandsConfigSerde::load(WriteConfig::Dont).map(|_| ()),
+    );
+    check(
+        AppearanceConfigSerde::LABEL,
+        AppearanceConfig::load(WriteConfig::Dont).map(|_| ()),
+    );
+    check(
+        PagesConfig::LABEL,
+        PagesConfig::load(WriteConfig::Dont).map(|_| ()),
+    );
+    check(
+        KeyBindingsConfigSerde::LABEL,
+        KeyBindingsConfig::load(WriteConfig::Dont).map(|_| ()),
+    );
+    check(
+        CommandBindingsSerde::LABEL,
+        CommandBindingsSerde::load(WriteConfig::Dont).and_then(|commandbindings| {
+            (*commandbindings)
+                .into()
+                .map(|_| ())
+                .map_err(|issue| issue.in_config(CommandBindingsSerde::LABEL).into())
+        }),
+    );
+    check(Search::LABEL, Search::load(WriteConfig::Dont).map(|_| ()));
+
+    // invalid patterns are skipped when loading, but are still reported here
+    match FiltersConfig::load(WriteConfig::Dont) {
+        Ok(mut filters) => issues.extend(filters.compile()),
+        Err(e) => issues.push(ConfigIssue::from_error(FiltersConfig::LABEL, e)),
+    }
+
+    issues
+}
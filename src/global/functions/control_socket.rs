@@ -0,0 +1,190 @@
+use crate::{
+    config::MainConfig,
+    global::{
+        functions::{apply_envs, data_dir},
+        structs::*,
+    },
+};
+use serde::Serialize;
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{BufRead, BufReader, Write},
+    mem,
+    os::unix::net::{UnixListener, UnixStream},
+    sync::{
+        mpsc::{self, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+use tui_additions::framework::Framework;
+
+/// how long a connected program waits for its command to finish running
+const REPLY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// reply sent to programs after their commands are ran
+#[derive(Serialize)]
+struct RemoteStatus<'a> {
+    page: &'a Page,
+    message: &'a Message,
+    url: Option<String>,
+}
+
+/// starts listening for commands on the control socket, if it is enabled in main config
+pub fn start_control_socket(framework: &mut Framework) {
+    if !framework
+        .data
+        .global
+        .get::<MainConfig>()
+        .unwrap()
+        .control_socket
+    {
+        return;
+    }
+
+    let path = data_dir().join(ControlSocket::PATH);
+    if path.exists() {
+        // the socket file is left behind if the last instance did not exit normally
+        if UnixStream::connect(&path).is_ok() {
+            *framework.data.global.get_mut::<Message>().unwrap() = Message::Error(String::from(
+                "Control socket is already used by another instance",
+            ));
+            return;
+        }
+        let _ = fs::remove_file(&path);
+    }
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(listener) => listener,
+        Err(e) => {
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Error(format!("Cannot start control socket: {e}"));
+            return;
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let sender = sender.clone();
+            thread::spawn(move || {
+                let _ = handle_connection(stream, sender);
+            });
+        }
+    });
+
+    framework
+        .data
+        .global
+        .insert::<ControlSocket>(ControlSocket {
+            receiver: Some(Arc::new(Mutex::new(receiver))),
+            waiting: Vec::new(),
+        });
+}
+
+/// every line sent to the socket is a command, which is replied with the status after it is ran
+fn handle_connection(
+    stream: UnixStream,
+    sender: Sender<RemoteRequest>,
+) -> Result<(), Box<dyn Error>> {
+    let mut writer = stream.try_clone()?;
+
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (reply_sender, reply_receiver) = mpsc::channel();
+        sender.send((line, reply_sender))?;
+        writeln!(writer, "{}", reply_receiver.recv_timeout(REPLY_TIMEOUT)?)?;
+    }
+
+    Ok(())
+}
+
+/// replies to programs which commands have finished running, then queues commands received from
+/// the control socket, returns true if any commands are queued
+// should only be called when there are no tasks left, so that the reply shows the page and
+// message after the commands are ran
+pub fn handle_control_socket(framework: &mut Framework) -> bool {
+    let control_socket = match framework.data.global.get_mut::<ControlSocket>() {
+        Some(control_socket) => control_socket,
+        None => return false,
+    };
+    let receiver = match &control_socket.receiver {
+        Some(receiver) => receiver.clone(),
+        None => return false,
+    };
+    let waiting = mem::take(&mut control_socket.waiting);
+
+    if !waiting.is_empty() {
+        let status = serde_json::to_string(&RemoteStatus {
+            page: framework.data.state.get::<Page>().unwrap(),
+            message: framework.data.global.get::<Message>().unwrap(),
+            url: env::var("url").ok(),
+        })
+        .unwrap();
+        waiting.into_iter().for_each(|sender| {
+            let _ = sender.send(status.clone());
+        });
+    }
+
+    let requests = receiver.lock().unwrap().try_iter().collect::<Vec<_>>();
+    if requests.is_empty() {
+        return false;
+    }
+
+    for (command, reply_sender) in requests {
+        framework
+            .data
+            .state
+            .get_mut::<Tasks>()
+            .unwrap()
+            .priority
+            .push(Task::Command(apply_envs(command)));
+        framework
+            .data
+            .global
+            .get_mut::<ControlSocket>()
+            .unwrap()
+            .waiting
+            .push(reply_sender);
+    }
+
+    true
+}
+
+/// removes the socket file if this instance is listening on it
+pub fn stop_control_socket(framework: &Framework) -> Result<(), Box<dyn Error>> {
+    if let Some(ControlSocket {
+        receiver: Some(_), ..
+    }) = framework.data.global.get::<ControlSocket>()
+    {
+        fs::remove_file(data_dir().join(ControlSocket::PATH))?;
+    }
+
+    Ok(())
+}
+
+/// sends a command to the running instance through the control socket, returns the json status
+/// it replies with
+pub fn send_remote_command(command: &str) -> Result<String, Box<dyn Error>> {
+    let mut stream = UnixStream::connect(data_dir().join(ControlSocket::PATH)).map_err(|e| {
+        Errors::StringError(format!(
+            "Cannot connect to a running instance ({e}), is `control_socket` enabled in main.yml?"
+        ))
+    })?;
+    writeln!(stream, "{}", command.replace('\n', " "))?;
+
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply)?;
+    if reply.is_empty() {
+        return Err(Errors::StrError("No reply from the running instance").into());
+    }
+
+    Ok(reply.trim_end().to_string())
+}
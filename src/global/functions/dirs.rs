@@ -0,0 +1,80 @@
+use home::home_dir;
+use std::{env, path::PathBuf, sync::OnceLock};
+
+// set by `--config-dir`, `--cache-dir` and `--data-dir`
+static CONFIG_DIR: OnceLock<PathBuf> = OnceLock::new();
+static CACHE_DIR: OnceLock<PathBuf> = OnceLock::new();
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// finds a directory of the app, in order of
+///  1. the command line option
+///  2. `YOUTUBE_TUI_*_DIR` env
+///  3. `youtube-tui` in `XDG_*_HOME`
+///  4. `youtube-tui` in the default location in home dir
+fn resolve(overridden: &OnceLock<PathBuf>, env_key: &str, xdg_key: &str, default: &str) -> PathBuf {
+    if let Some(path) = overridden.get() {
+        return path.clone();
+    }
+
+    if let Some(path) = env::var_os(env_key).filter(|path| !path.is_empty()) {
+        return PathBuf::from(path);
+    }
+
+    let legacy = home_dir().unwrap().join(default).join("youtube-tui");
+    match env::var_os(xdg_key).filter(|path| !path.is_empty()) {
+        Some(xdg) => {
+            let path = PathBuf::from(xdg).join("youtube-tui");
+            // keep using files created before XDG dirs are supported
+            if !path.exists() && legacy.exists() {
+                legacy
+            } else {
+                path
+            }
+        }
+        None => legacy,
+    }
+}
+
+/// config files, `~/.config/youtube-tui/` by default
+pub fn config_dir() -> PathBuf {
+    resolve(
+        &CONFIG_DIR,
+        "YOUTUBE_TUI_CONFIG_DIR",
+        "XDG_CONFIG_HOME",
+        ".config",
+    )
+}
+
+/// info and thumbnails of loaded items, removed on exit, `~/.cache/youtube-tui/` by default
+pub fn cache_dir() -> PathBuf {
+    resolve(
+        &CACHE_DIR,
+        "YOUTUBE_TUI_CACHE_DIR",
+        "XDG_CACHE_HOME",
+        ".cache",
+    )
+}
+
+/// history, library, subscriptions and other saved collections, `~/.local/share/youtube-tui/` by
+/// default
+pub fn data_dir() -> PathBuf {
+    resolve(
+        &DATA_DIR,
+        "YOUTUBE_TUI_DATA_DIR",
+        "XDG_DATA_HOME",
+        ".local/share",
+    )
+}
+
+/// can only be set once, before anything is loaded
+pub fn set_config_dir(path: PathBuf) {
+    let _ = CONFIG_DIR.set(path);
+}
+
+pub fn set_cache_dir(path: PathBuf) {
+    let _ = CACHE_DIR.set(path);
+}
+
+pub fn set_data_dir(path: PathBuf) {
+    let _ = DATA_DIR.set(path);
+}
@@ -1,7 +1,11 @@
-use crate::global::structs::Item;
+use crate::global::{
+    functions::cache_dir,
+    structs::{CommunityPostItem, Item, PostAttachment},
+};
 use futures::future::join_all;
-use std::{error::Error, io::Cursor, thread};
-use tokio::runtime::Runtime;
+use std::{error::Error, io::Cursor};
+
+use super::runtime;
 
 pub struct DownloadRequest {
     pub url: String,
@@ -36,6 +40,15 @@ impl From<&Item> for Option<DownloadRequest> {
                 url: fullchannel.thumbnail_url.clone(),
                 id: fullchannel.id.clone(),
             },
+            // only posts with an attached image has a thumbnail
+            Item::CommunityPost(CommunityPostItem {
+                id,
+                attachment: Some(PostAttachment::Image(url)),
+                ..
+            }) => DownloadRequest {
+                url: url.clone(),
+                id: id.clone(),
+            },
             _ => return None,
         })
     }
@@ -48,21 +61,16 @@ pub fn download_all_images(downloads: Vec<Option<DownloadRequest>>) {
         return;
     }
 
-    thread::spawn(move || {
-        let rt: Runtime = tokio::runtime::Runtime::new().unwrap();
-
-        rt.block_on(download_all_images_async(
-            downloads.into_iter().flatten().collect(),
-        ));
-    });
+    runtime().spawn(download_all_images_async(
+        downloads.into_iter().flatten().collect(),
+    ));
 }
 
 // Create a `download_single()`s and join them into 1 future
 // So all downloads will happen at the same time without waiting
 async fn download_all_images_async(downloads: Vec<DownloadRequest>) {
     let mut actions = Vec::new();
-    let mut path = home::home_dir().expect("Cannot get your home directory");
-    path.push(".cache/youtube-tui/thumbnails/");
+    let path = cache_dir().join("thumbnails");
 
     for download in downloads.into_iter() {
         let path = path.clone().join(download.id);
@@ -79,7 +87,7 @@ async fn download_all_images_async(downloads: Vec<DownloadRequest>) {
 }
 
 // Download a single file
-async fn download_single(url: String, path: String) -> Result<(), Box<dyn Error>> {
+async fn download_single(url: String, path: String) -> Result<(), Box<dyn Error + Send + Sync>> {
     let response = reqwest::get(url).await?;
     if !response.status().is_success() {
         return Ok(());
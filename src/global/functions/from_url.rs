@@ -1,72 +1,413 @@
-/// gets the channel id from it's url
-pub fn from_channel_url(identifier: &str) -> Result<String, String> {
-    // a youtube channel id is exactly 24 characters long, so if the identifier to 24 chars
-    // long, then just assume its the channel id
-    if identifier.len() == 24 {
-        Ok(identifier.to_string())
-    } else {
-        // the channel id comes after `/channel/` in an url
-        let index = if let Some(index) = identifier.find("/channel/") {
-            // if there isnt 24 characters after `/channel/`, that url must not have
-            // contained a channel id
-            if identifier.len() < index + 33 {
-                return Err(format!("Cannot find channel id from string `{identifier}`"));
+use crate::global::structs::Errors;
+use reqwest::Url;
+use serde::Deserialize;
+use std::error::Error;
+
+/// what an url or a raw id points to
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum UrlTarget {
+    /// video id and the time to start playing from in seconds
+    Video(String, Option<u64>),
+    Playlist(String),
+    Channel(String),
+    /// channel handle (`@name`), custom url (`c/name`) or username (`user/name`), which has to be
+    /// resolved to a channel id
+    ChannelPath(String),
+}
+
+impl UrlTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Video(_, _) => "video",
+            Self::Playlist(_) => "playlist",
+            Self::Channel(_) | Self::ChannelPath(_) => "channel",
+        }
+    }
+}
+
+/// characters allowed in video, playlist and channel ids
+fn is_id(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn is_video_id(s: &str) -> bool {
+    s.len() == 11 && is_id(s)
+}
+
+fn is_channel_id(s: &str) -> bool {
+    s.len() == 24 && s.starts_with("UC") && is_id(s)
+}
+
+fn is_playlist_id(s: &str) -> bool {
+    // normal playlists are 34 characters long, mixes, uploads and liked videos lists are
+    // identified by their prefixes
+    is_id(s)
+        && (s.len() == 34
+            || ["PL", "OL", "UU", "FL", "LL", "RD"]
+                .iter()
+                .any(|prefix| s.len() > 12 && s.starts_with(prefix)))
+}
+
+/// parses a timestamp like `90`, `90s`, `1m30s` or `1h2m3s` into seconds
+fn parse_timestamp(s: &str) -> Option<u64> {
+    if let Ok(secs) = s.parse() {
+        return Some(secs);
+    }
+
+    let mut total = 0;
+    let mut number = String::new();
+    for c in s.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'h' | 'm' | 's' => {
+                let value = number.parse::<u64>().ok()?;
+                number.clear();
+                total += match c {
+                    'h' => value * 3600,
+                    'm' => value * 60,
+                    _ => value,
+                };
             }
-            index + 9
-        } else {
-            return Err(format!("Cannot find channel id from string `{identifier}`"));
-        };
+            _ => return None,
+        }
+    }
+
+    if number.is_empty() {
+        Some(total)
+    } else {
+        None
+    }
+}
 
-        Ok(identifier[index..index + 24].to_string())
+/// parses an url, which may be without the scheme
+fn to_url(s: &str) -> Option<Url> {
+    if s.contains("://") {
+        Url::parse(s).ok()
+    } else {
+        Url::parse(&format!("https://{s}")).ok()
     }
 }
 
-pub fn from_video_url(identifier: &str) -> Result<String, String> {
-    // a youtube video id is exactly 11 characters long, so if the identifier to 11 chars
-    // long, then just assume its the video id
-    if identifier.len() == 11 {
-        Ok(identifier.to_string())
+/// classifies an url or a raw id, urls from YouTube (including `m.` and `music.` hosts),
+/// Invidious and Piped instances are supported
+pub fn parse_url(identifier: &str) -> Result<UrlTarget, String> {
+    let identifier = identifier.trim();
+    let error = || format!("Cannot understand url or id `{identifier}`");
+
+    if identifier.starts_with('@') && !identifier.contains('/') {
+        return Ok(UrlTarget::ChannelPath(identifier.to_string()));
+    }
+    if is_video_id(identifier) {
+        return Ok(UrlTarget::Video(identifier.to_string(), None));
+    }
+    if is_channel_id(identifier) {
+        return Ok(UrlTarget::Channel(identifier.to_string()));
+    }
+    if is_playlist_id(identifier) {
+        return Ok(UrlTarget::Playlist(identifier.to_string()));
+    }
+
+    let url = to_url(identifier).ok_or_else(error)?;
+
+    let query = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.to_string())
+    };
+    // the start time can be in the query (`t`, `start`) or the fragment (`#t=`)
+    let start = query("t")
+        .or_else(|| query("start"))
+        .or_else(|| {
+            url.fragment()
+                .and_then(|fragment| fragment.strip_prefix("t="))
+                .map(str::to_string)
+        })
+        .and_then(|t| parse_timestamp(&t));
+    let segments = url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let target = if url
+        .host_str()
+        .is_some_and(|host| host == "youtu.be" || host.ends_with(".youtu.be"))
+    {
+        segments
+            .first()
+            .map(|id| UrlTarget::Video(id.to_string(), start))
     } else {
-        // the id can come after "?v=" in an url
-        let index = if let Some(index) = identifier.find("?v=") {
-            if identifier.len() < index + 15 {
-                return Err(format!("Cannot find video id from string `{identifier}`"));
+        match segments.as_slice() {
+            ["watch", ..] => match (query("v"), query("list")) {
+                (Some(id), _) => Some(UrlTarget::Video(id, start)),
+                (None, Some(id)) => Some(UrlTarget::Playlist(id)),
+                (None, None) => None,
+            },
+            ["shorts" | "live" | "embed" | "v" | "e", id, ..] => {
+                Some(UrlTarget::Video(id.to_string(), start))
+            }
+            ["playlist", ..] => query("list").map(UrlTarget::Playlist),
+            ["channel" | "browse", id, ..] => Some(UrlTarget::Channel(id.to_string())),
+            [kind @ ("c" | "user"), name, ..] => {
+                Some(UrlTarget::ChannelPath(format!("{kind}/{name}")))
             }
-            index + 3
-        // and also "youtu.be/"
-        } else if let Some(index) = identifier.find("youtu.be/") {
-            if identifier.len() < index + 21 {
-                return Err(format!("Cannot find video id from string `{identifier}`"));
+            [handle, ..] if handle.starts_with('@') => {
+                Some(UrlTarget::ChannelPath(handle.to_string()))
             }
-            index + 9
-        } else {
-            return Err(format!("Cannot find video id from string `{identifier}`"));
-        };
+            _ => None,
+        }
+    };
 
-        Ok(identifier[index..index + 11].to_string())
+    match target {
+        Some(UrlTarget::Video(id, start)) if is_video_id(&id) => Ok(UrlTarget::Video(id, start)),
+        Some(UrlTarget::Playlist(id)) if is_id(&id) => Ok(UrlTarget::Playlist(id)),
+        Some(UrlTarget::Channel(id)) if is_channel_id(&id) => Ok(UrlTarget::Channel(id)),
+        Some(UrlTarget::ChannelPath(path)) => Ok(UrlTarget::ChannelPath(path)),
+        _ => Err(error()),
     }
 }
 
+#[derive(Deserialize)]
+struct ResolvedUrl {
+    ucid: Option<String>,
+    error: Option<String>,
+}
+
+/// resolves a channel handle, custom url or username to a channel id with the invidious api
+pub fn resolve_channel_path(server: &str, path: &str) -> Result<String, Box<dyn Error>> {
+    let url = Url::parse_with_params(
+        &format!("{server}/api/v1/resolveurl"),
+        &[("url", format!("https://www.youtube.com/{path}"))],
+    )?;
+
+    match serde_json::from_str::<ResolvedUrl>(&reqwest::blocking::get(url)?.text()?)? {
+        ResolvedUrl { ucid: Some(id), .. } => Ok(id),
+        ResolvedUrl {
+            error: Some(error), ..
+        } => Err(Errors::StringError(error).into()),
+        _ => Err(Errors::StringError(format!("`{path}` is not a channel")).into()),
+    }
+}
+
+/// gets the channel id from an url, id or handle
+pub fn from_channel_url(server: &str, identifier: &str) -> Result<String, String> {
+    match parse_url(identifier)? {
+        UrlTarget::Channel(id) => Ok(id),
+        UrlTarget::ChannelPath(path) => resolve_channel_path(server, &path)
+            .map_err(|e| format!("Cannot resolve channel `{path}`: {e}")),
+        target => Err(format!(
+            "`{identifier}` is a {} url, not a channel",
+            target.as_str()
+        )),
+    }
+}
+
+/// gets the video id and start time from an url or id
+pub fn from_video_url(identifier: &str) -> Result<(String, Option<u64>), String> {
+    match parse_url(identifier)? {
+        UrlTarget::Video(id, start) => Ok((id, start)),
+        target => Err(format!(
+            "`{identifier}` is a {} url, not a video",
+            target.as_str()
+        )),
+    }
+}
+
+/// gets the playlist id from an url or id
 pub fn from_playlist_url(identifier: &str) -> Result<String, String> {
-    // a youtube playlist id is exactly 34 characters long, so if the identifier to 34 chars
-    // long, then just assume its the playlist id
-    if identifier.len() == 34 {
-        Ok(identifier.to_string())
-    } else {
-        // the id can come after "list=" in urls
-        let index = if let Some(index) = identifier.find("list=") {
-            if identifier.len() < index + 39 {
-                return Err(format!(
-                    "Cannot find plalyist id from string `{identifier}`"
-                ));
-            }
-            index + 5
-        } else {
-            return Err(format!(
-                "Cannot find playlist id from string `{identifier}`"
-            ));
-        };
-
-        Ok(identifier[index..index + 34].to_string())
+    match parse_url(identifier)? {
+        UrlTarget::Playlist(id) => Ok(id),
+        // watch urls can also contain a playlist
+        UrlTarget::Video(_, _) => to_url(identifier.trim())
+            .and_then(|url| {
+                url.query_pairs()
+                    .find(|(key, _)| key == "list")
+                    .map(|(_, id)| id.to_string())
+            })
+            .ok_or_else(|| format!("`{identifier}` is a video url, not a playlist")),
+        target => Err(format!(
+            "`{identifier}` is a {} url, not a playlist",
+            target.as_str()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIDEO: &str = "dQw4w9WgXcQ";
+    const CHANNEL: &str = "UCuAXFkgsw1L7xaCfnd5JJOw";
+    const PLAYLIST: &str = "PLFgquLnL59alCl_2TQvOiD5Vgm1hCaGSI";
+
+    fn video(start: Option<u64>) -> UrlTarget {
+        UrlTarget::Video(VIDEO.to_string(), start)
+    }
+
+    fn channel_path(path: &str) -> UrlTarget {
+        UrlTarget::ChannelPath(path.to_string())
+    }
+
+    #[test]
+    fn parses_urls() {
+        let channel = UrlTarget::Channel(CHANNEL.to_string());
+        let playlist = UrlTarget::Playlist(PLAYLIST.to_string());
+
+        let cases = [
+            // raw ids and handles
+            (VIDEO.to_string(), video(None)),
+            (CHANNEL.to_string(), channel.clone()),
+            (PLAYLIST.to_string(), playlist.clone()),
+            (String::from("@RickAstleyYT"), channel_path("@RickAstleyYT")),
+            // videos
+            (
+                format!("https://www.youtube.com/watch?v={VIDEO}"),
+                video(None),
+            ),
+            (format!("youtube.com/watch?v={VIDEO}"), video(None)),
+            (
+                format!("https://m.youtube.com/watch?v={VIDEO}"),
+                video(None),
+            ),
+            (
+                format!("https://music.youtube.com/watch?v={VIDEO}"),
+                video(None),
+            ),
+            (format!("https://youtu.be/{VIDEO}"), video(None)),
+            (
+                format!("https://www.youtube.com/shorts/{VIDEO}"),
+                video(None),
+            ),
+            (
+                format!("https://www.youtube.com/live/{VIDEO}?feature=share"),
+                video(None),
+            ),
+            (
+                format!("https://www.youtube.com/embed/{VIDEO}"),
+                video(None),
+            ),
+            (format!("https://yewtu.be/watch?v={VIDEO}"), video(None)),
+            (format!("https://piped.video/watch?v={VIDEO}"), video(None)),
+            (
+                format!("https://www.youtube.com/watch?v={VIDEO}&list={PLAYLIST}"),
+                video(None),
+            ),
+            // start times
+            (format!("https://youtu.be/{VIDEO}?t=90"), video(Some(90))),
+            (
+                format!("https://www.youtube.com/watch?v={VIDEO}&t=1m30s"),
+                video(Some(90)),
+            ),
+            (
+                format!("https://www.youtube.com/watch?v={VIDEO}&t=1h2m3s"),
+                video(Some(3723)),
+            ),
+            (
+                format!("https://www.youtube.com/watch?v={VIDEO}#t=45s"),
+                video(Some(45)),
+            ),
+            (
+                format!("https://www.youtube.com/embed/{VIDEO}?start=30"),
+                video(Some(30)),
+            ),
+            (
+                format!("https://www.youtube.com/watch?v={VIDEO}&t=later"),
+                video(None),
+            ),
+            // playlists
+            (
+                format!("https://www.youtube.com/playlist?list={PLAYLIST}"),
+                playlist.clone(),
+            ),
+            (
+                format!("https://music.youtube.com/playlist?list={PLAYLIST}"),
+                playlist.clone(),
+            ),
+            (
+                format!("https://piped.video/playlist?list={PLAYLIST}"),
+                playlist.clone(),
+            ),
+            (
+                format!("https://www.youtube.com/watch?list={PLAYLIST}"),
+                playlist,
+            ),
+            // channels
+            (
+                format!("https://www.youtube.com/channel/{CHANNEL}"),
+                channel.clone(),
+            ),
+            (
+                format!("https://www.youtube.com/channel/{CHANNEL}/videos"),
+                channel.clone(),
+            ),
+            (
+                format!("https://music.youtube.com/browse/{CHANNEL}"),
+                channel.clone(),
+            ),
+            (format!("https://yewtu.be/channel/{CHANNEL}"), channel),
+            (
+                String::from("https://www.youtube.com/@RickAstleyYT"),
+                channel_path("@RickAstleyYT"),
+            ),
+            (
+                String::from("https://m.youtube.com/@RickAstleyYT/videos"),
+                channel_path("@RickAstleyYT"),
+            ),
+            (
+                String::from("https://www.youtube.com/c/RickAstley"),
+                channel_path("c/RickAstley"),
+            ),
+            (
+                String::from("https://www.youtube.com/user/RickAstleyVEVO"),
+                channel_path("user/RickAstleyVEVO"),
+            ),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(parse_url(&url), Ok(expected), "{url}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_urls() {
+        for url in [
+            "",
+            "not an url",
+            "https://www.youtube.com/",
+            "https://www.youtube.com/watch?v=short",
+            "https://www.youtube.com/channel/notachannel",
+            "https://www.youtube.com/feed/trending",
+        ] {
+            assert!(parse_url(url).is_err(), "{url}");
+        }
+    }
+
+    #[test]
+    fn parses_timestamps() {
+        for (timestamp, expected) in [
+            ("90", Some(90)),
+            ("90s", Some(90)),
+            ("1m30s", Some(90)),
+            ("1h2m3s", Some(3723)),
+            ("2m", Some(120)),
+            ("1m30", None),
+            ("1x", None),
+            ("m", None),
+        ] {
+            assert_eq!(parse_timestamp(timestamp), expected, "{timestamp}");
+        }
+    }
+
+    #[test]
+    fn playlists_in_watch_urls() {
+        assert_eq!(
+            from_playlist_url(&format!(
+                "https://www.youtube.com/watch?v={VIDEO}&list={PLAYLIST}"
+            )),
+            Ok(PLAYLIST.to_string())
+        );
+        assert!(from_playlist_url(&format!("https://youtu.be/{VIDEO}")).is_err());
+        assert!(from_video_url(CHANNEL).is_err());
     }
 }
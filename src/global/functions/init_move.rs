@@ -1,11 +1,9 @@
+use crate::global::functions::{cache_dir, data_dir};
 use std::fs;
 
-use home::home_dir;
-
 pub fn init_move() {
-    let home_dir = home_dir().unwrap();
-    let store_dir = home_dir.join(".local/share/youtube-tui/");
-    let cache_dir = home_dir.join(".cache/youtube-tui");
+    let store_dir = data_dir();
+    let cache_dir = cache_dir();
 
     let store_info_dir = store_dir.join("info/");
     let cache_info_dir = cache_dir.join("info/");
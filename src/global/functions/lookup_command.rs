@@ -0,0 +1,201 @@
+use crate::{
+    config::{Images, MainConfig, Search, WriteConfig},
+    global::{
+        functions::{from_channel_url, from_playlist_url, from_video_url, load_playlist_rest},
+        structs::{Item, Library},
+        traits::{Collection, ConfigTrait},
+    },
+    CliArgs,
+};
+use invidious::reqwest::blocking::Client;
+use std::error::Error;
+
+/// how lookup results are printed
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Json,
+    /// one item per line, with tab separated columns
+    Table,
+}
+
+/// lookup commands - commands from the command line which fetches items and prints them without
+/// launching the TUI, returns `None` if the command is not a lookup command
+// `search`, `video`, `playlist` and `channel` are also loadpage commands, so they are only
+// lookups when `--json` or `--table` is given
+pub fn lookup_command(cli: &CliArgs) -> Option<Result<String, Box<dyn Error>>> {
+    let mut format = None;
+    let args = cli
+        .command
+        .split_ascii_whitespace()
+        .filter(|arg| match *arg {
+            "--json" => {
+                format = Some(OutputFormat::Json);
+                false
+            }
+            "--table" => {
+                format = Some(OutputFormat::Table);
+                false
+            }
+            _ => true,
+        })
+        .collect::<Vec<_>>();
+
+    let items = match (args.as_slice(), format) {
+        (["library", "list"], _) => Ok(Library::load()),
+        (_, None) => return None,
+        (["search", query @ ..], Some(_)) if !query.is_empty() => {
+            mainconfig(cli).and_then(|mainconfig| search(&mainconfig, &query.join(" ")))
+        }
+        (["video", identifier], Some(_)) => {
+            mainconfig(cli).and_then(|mainconfig| video(&mainconfig, identifier))
+        }
+        (["playlist", identifier], Some(_)) => {
+            mainconfig(cli).and_then(|mainconfig| playlist(&mainconfig, identifier))
+        }
+        (["channel", identifier], Some(_)) => {
+            mainconfig(cli).and_then(|mainconfig| channel(&mainconfig, identifier))
+        }
+        _ => return None,
+    };
+
+    Some(items.map(|items| {
+        match format.unwrap_or(OutputFormat::Table) {
+            OutputFormat::Json => serde_json::to_string_pretty(&items).unwrap(),
+            OutputFormat::Table => items
+                .iter()
+                .flat_map(table_rows)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }))
+}
+
+/// main config without writing to it, the TUI is not launched so thumbnails are not downloaded
+fn mainconfig(cli: &CliArgs) -> Result<MainConfig, Box<dyn Error>> {
+    let mut mainconfig = *MainConfig::load(WriteConfig::Dont)?;
+    cli.apply(&mut mainconfig);
+    mainconfig.images = Images::None;
+    Ok(mainconfig)
+}
+
+fn client(mainconfig: &MainConfig) -> Client {
+    Client::new(mainconfig.invidious_instance.clone())
+}
+
+/// searches with the filters in `search.yml`
+fn search(mainconfig: &MainConfig, query: &str) -> Result<Vec<Item>, Box<dyn Error>> {
+    let search = Search {
+        query: query.to_string(),
+        page: 1,
+        ..*Search::load(WriteConfig::Dont)?
+    };
+
+    Ok(client(mainconfig)
+        .search(Some(&search.to_string()))?
+        .items
+        .into_iter()
+        .map(|item| Item::from_search_item(item, mainconfig.image_index))
+        .filter(|item| !item.is_unknown())
+        .collect())
+}
+
+fn video(mainconfig: &MainConfig, identifier: &str) -> Result<Vec<Item>, Box<dyn Error>> {
+    let (id, _) = from_video_url(identifier)?;
+
+    Ok(vec![Item::from_full_video(
+        client(mainconfig).video(&id, None)?,
+        mainconfig.image_index,
+    )])
+}
+
+/// loads the playlist with all of its videos
+fn playlist(mainconfig: &MainConfig, identifier: &str) -> Result<Vec<Item>, Box<dyn Error>> {
+    let client = client(mainconfig);
+    let id = from_playlist_url(identifier)?;
+
+    let mut playlist =
+        Item::from_full_playlist(client.playlist(&id, None)?, mainconfig.image_index);
+    if let Item::FullPlaylist(fullplaylist) = &mut playlist {
+        if fullplaylist.videos.len() < fullplaylist.video_count as usize {
+            for batch in load_playlist_rest(
+                &client,
+                &id,
+                fullplaylist.videos.len(),
+                2,
+                fullplaylist.video_count as usize,
+                mainconfig,
+            ) {
+                fullplaylist.videos.extend(batch?);
+            }
+        }
+    }
+
+    Ok(vec![playlist])
+}
+
+fn channel(mainconfig: &MainConfig, identifier: &str) -> Result<Vec<Item>, Box<dyn Error>> {
+    let client = client(mainconfig);
+    let id = from_channel_url(&client.server, identifier)?;
+
+    Ok(vec![Item::from_full_channel(
+        client.channel(&id, None)?,
+        mainconfig.image_index,
+    )])
+}
+
+/// columns are type, id, title, channel and length of videos and playlists, channels have their
+/// name, subscribers and views (or number of videos) instead, a playlist is followed by rows of its
+/// videos
+fn table_rows(item: &Item) -> Vec<String> {
+    let row = |columns: [&str; 5]| columns.join("\t");
+
+    match item {
+        Item::MiniVideo(video) => vec![row([
+            "video",
+            &video.id,
+            &video.title,
+            &video.channel,
+            &video.length,
+        ])],
+        Item::FullVideo(video) => vec![row([
+            "video",
+            &video.id,
+            &video.title,
+            &video.channel,
+            &video.length,
+        ])],
+        Item::MiniPlaylist(playlist) => vec![row([
+            "playlist",
+            &playlist.id,
+            &playlist.title,
+            &playlist.channel,
+            &format!("{} videos", playlist.video_count),
+        ])],
+        Item::FullPlaylist(playlist) => {
+            let mut rows = vec![row([
+                "playlist",
+                &playlist.id,
+                &playlist.title,
+                &playlist.channel,
+                &format!("{} videos", playlist.video_count),
+            ])];
+            rows.extend(playlist.videos.iter().flat_map(table_rows));
+            rows
+        }
+        Item::MiniChannel(channel) => vec![row([
+            "channel",
+            &channel.id,
+            &channel.name,
+            &channel.sub_count_text,
+            &format!("{} videos", channel.video_count),
+        ])],
+        Item::FullChannel(channel) => vec![row([
+            "channel",
+            &channel.id,
+            &channel.name,
+            &channel.sub_count_text,
+            &format!("{} views", channel.total_views),
+        ])],
+        _ => Vec::new(),
+    }
+}
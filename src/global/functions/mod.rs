@@ -5,6 +5,8 @@ mod viewcount_text;
 pub use viewcount_text::viewcount_text;
 mod date_text;
 pub use date_text::date_text;
+mod runtime;
+pub use runtime::runtime;
 mod download_all_images;
 pub use download_all_images::download_all_images;
 mod popup_area;
@@ -29,3 +31,29 @@ mod init_move;
 pub use init_move::*;
 mod singleitem_load;
 pub use singleitem_load::*;
+mod channel_feed;
+pub use channel_feed::*;
+mod channel_items;
+pub use channel_items::*;
+mod refresh_subscriptions;
+pub use refresh_subscriptions::*;
+mod refresh_saved_searches;
+pub use refresh_saved_searches::*;
+mod search_suggestions;
+pub use search_suggestions::*;
+mod dirs;
+pub use dirs::*;
+mod profiles;
+pub use profiles::*;
+mod yaml_edit;
+pub use yaml_edit::*;
+mod check_configs;
+pub use check_configs::*;
+mod watch_configs;
+pub use watch_configs::*;
+mod lookup_command;
+pub use lookup_command::*;
+#[cfg(unix)]
+mod control_socket;
+#[cfg(unix)]
+pub use control_socket::*;
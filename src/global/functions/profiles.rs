@@ -0,0 +1,180 @@
+use super::config_dir;
+use serde_yaml::Value;
+use std::{error::Error, fs, path::PathBuf, sync::RwLock};
+
+/// config files that can be overlaid by a profile
+pub const PROFILE_FILES: &[&str] = &[
+    "main",
+    "appearance",
+    "pages",
+    "keybindings",
+    "commandbindings",
+];
+
+// set by `--profile` or the `profile` command, `None` uses the config files as they are
+static PROFILE: RwLock<Option<String>> = RwLock::new(None);
+
+/// profiles are stored in `profiles/` in the config dir, each profile is a directory with the
+/// config files it overlays
+pub fn profiles_dir() -> PathBuf {
+    config_dir().join("profiles")
+}
+
+/// names of all profiles
+pub fn profiles() -> Vec<String> {
+    let mut profiles = fs::read_dir(profiles_dir())
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    profiles.sort();
+    profiles
+}
+
+/// the current profile
+pub fn profile() -> Option<String> {
+    PROFILE.read().unwrap().clone()
+}
+
+/// switches to another profile, `None` for no profile, config files have to be reloaded after
+pub fn set_profile(name: Option<String>) -> Result<(), Box<dyn Error>> {
+    if let Some(name) = &name {
+        if !profiles().contains(name) {
+            return Err(format!(
+                "Profile `{name}` does not exist, create it at {}",
+                profiles_dir().join(name).display()
+            )
+            .into());
+        }
+    }
+
+    *PROFILE.write().unwrap() = name;
+    Ok(())
+}
+
+/// label of the config file in the current profile which overlays the config file of `label`,
+/// such as `profiles/laptop/main`
+pub fn profile_label(label: &str) -> Option<String> {
+    if !PROFILE_FILES.contains(&label) {
+        return None;
+    }
+
+    profile().map(|name| format!("profiles/{name}/{label}"))
+}
+
+/// values in `overlay` replace the ones in `base`, mappings are merged so that a profile only
+/// needs to contain the options it changes
+pub fn merge_values(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Mapping(base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_values(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        // empty files
+        (_, Value::Null) => {}
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// splits `value` into the parts which are in `overlay` and the rest, so that options in a
+/// profile are saved to the profile
+pub fn split_values(value: Value, overlay: &Value) -> (Value, Value) {
+    match (value, overlay) {
+        (Value::Mapping(value), Value::Mapping(overlay)) => {
+            let mut rest = serde_yaml::Mapping::new();
+            let mut overlaid = serde_yaml::Mapping::new();
+
+            for (key, value) in value {
+                match overlay.get(&key) {
+                    Some(overlay) => {
+                        let (value_rest, value_overlaid) = split_values(value, overlay);
+                        if !value_rest.as_mapping().is_some_and(|rest| rest.is_empty()) {
+                            rest.insert(key.clone(), value_rest);
+                        }
+                        overlaid.insert(key, value_overlaid);
+                    }
+                    None => {
+                        rest.insert(key, value);
+                    }
+                }
+            }
+
+            (Value::Mapping(rest), Value::Mapping(overlaid))
+        }
+        (value, Value::Null) => (value, Value::Null),
+        (value, _) => (Value::Mapping(serde_yaml::Mapping::new()), value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yaml(content: &str) -> Value {
+        serde_yaml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn merges_nested_overlays() {
+        let mut base = yaml("a: {b: 1, c: 2}\nd: 3");
+        merge_values(&mut base, yaml("a: {c: 5, e: 6}"));
+        assert_eq!(base, yaml("a: {b: 1, c: 5, e: 6}\nd: 3"));
+    }
+
+    #[test]
+    fn merges_scalars_over_mappings() {
+        let mut base = yaml("a: {b: 1}\nd: 3");
+        merge_values(&mut base, yaml("a: 2"));
+        assert_eq!(base, yaml("a: 2\nd: 3"));
+
+        let mut base = yaml("a: 1");
+        merge_values(&mut base, yaml("a: {b: 2}"));
+        assert_eq!(base, yaml("a: {b: 2}"));
+    }
+
+    #[test]
+    fn merges_empty_profile_files() {
+        let mut base = yaml("a: {b: 1}");
+        merge_values(&mut base, yaml(""));
+        assert_eq!(base, yaml("a: {b: 1}"));
+    }
+
+    #[test]
+    fn splits_nested_overlays() {
+        let value = yaml("a: {b: 1, c: 5, e: 6}\nd: 3");
+        let overlay = yaml("a: {c: 2, e: 2}");
+        let (rest, overlaid) = split_values(value.clone(), &overlay);
+        assert_eq!(rest, yaml("a: {b: 1}\nd: 3"));
+        assert_eq!(overlaid, yaml("a: {c: 5, e: 6}"));
+
+        // the value is the same after merging the parts again
+        let mut merged = rest;
+        merge_values(&mut merged, overlaid);
+        assert_eq!(merged, value);
+    }
+
+    #[test]
+    fn splits_mappings_under_scalars() {
+        // the whole mapping is saved to the profile, as the profile replaces all of it
+        let (rest, overlaid) = split_values(yaml("a: {b: 1}\nd: 3"), &yaml("a: 2"));
+        assert_eq!(rest, yaml("d: 3"));
+        assert_eq!(overlaid, yaml("a: {b: 1}"));
+    }
+
+    #[test]
+    fn splits_empty_profile_files() {
+        let (rest, overlaid) = split_values(yaml("a: {b: 1}"), &yaml(""));
+        assert_eq!(rest, yaml("a: {b: 1}"));
+        assert_eq!(overlaid, Value::Null);
+    }
+}
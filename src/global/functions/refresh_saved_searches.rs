@@ -0,0 +1,137 @@
+use crate::{
+    config::*,
+    global::{structs::*, traits::DataFile},
+};
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+use tui_additions::framework::Framework;
+
+/// starts a background refresh of all saved searches when the refresh interval has passed, and
+/// handles the results of a finished one
+pub fn refresh_saved_searches(framework: &mut Framework) {
+    let interval = framework
+        .data
+        .global
+        .get::<MainConfig>()
+        .unwrap()
+        .refresh_interval;
+    if interval == 0 {
+        return;
+    }
+
+    let refresher = framework.data.global.get_mut::<Refresher>().unwrap();
+
+    // a refresh is still running
+    if let Some(pending) = &refresher.pending_searches {
+        let results = match pending.lock().unwrap().try_recv() {
+            Ok(results) => results,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => Vec::new(),
+        };
+        refresher.pending_searches = None;
+        handle_new_results(framework, results);
+        return;
+    }
+
+    if refresher.last_search_refresh.elapsed() < Duration::from_secs(interval) {
+        return;
+    }
+
+    let searches = framework
+        .data
+        .global
+        .get::<SavedSearches>()
+        .unwrap()
+        .0
+        .iter()
+        .map(|saved| (saved.name.clone(), saved.search.to_string()))
+        .collect::<Vec<_>>();
+    let client = framework
+        .data
+        .global
+        .get::<InvidiousClient>()
+        .unwrap()
+        .0
+        .clone();
+    let image_index = framework
+        .data
+        .global
+        .get::<MainConfig>()
+        .unwrap()
+        .image_index;
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(
+            searches
+                .into_iter()
+                .filter_map(|(name, search)| {
+                    let items = client
+                        .search(Some(&search))
+                        .ok()?
+                        .items
+                        .into_iter()
+                        .map(|item| Item::from_search_item(item, image_index))
+                        .collect();
+                    Some((name, items))
+                })
+                .collect(),
+        );
+    });
+
+    let refresher = framework.data.global.get_mut::<Refresher>().unwrap();
+    refresher.last_search_refresh = Instant::now();
+    refresher.pending_searches = Some(Arc::new(Mutex::new(receiver)));
+}
+
+/// update unread results, then notify the user about results that were not seen before
+fn handle_new_results(framework: &mut Framework, mut results: SavedSearchesRefresh) {
+    let filters = framework.data.global.get::<FiltersConfig>().unwrap();
+    for (_, items) in results.iter_mut() {
+        items.retain(|item| filters.allows(item));
+    }
+
+    let saved_searches = framework.data.global.get_mut::<SavedSearches>().unwrap();
+    // (name of saved search, title of the new result)
+    let mut new = Vec::new();
+    for (name, items) in results.iter() {
+        // the search may have been removed while it is refreshing
+        let saved = match saved_searches.get_mut(name) {
+            Some(saved) => saved,
+            None => continue,
+        };
+        let ids = items.iter().filter_map(Item::id).collect::<Vec<_>>();
+        for id in saved.update(&ids) {
+            if let Some(item) = items.iter().find(|item| item.id() == Some(&id)) {
+                new.push((name, item.to_string()));
+            }
+        }
+    }
+    let _ = saved_searches.save();
+    framework
+        .data
+        .global
+        .get_mut::<LabelsRevision>()
+        .unwrap()
+        .bump();
+
+    if new.is_empty() {
+        return;
+    }
+
+    *framework.data.global.get_mut::<Message>().unwrap() = Message::Success(if new.len() == 1 {
+        format!("New result in {}: {}", new[0].0, new[0].1)
+    } else {
+        format!("{} new results in saved searches", new.len())
+    });
+    framework
+        .data
+        .state
+        .get_mut::<Tasks>()
+        .unwrap()
+        .priority
+        .push(Task::RenderAll);
+}
@@ -0,0 +1,208 @@
+use crate::{
+    config::*,
+    global::{functions::*, structs::*, traits::DataFile},
+};
+use std::{
+    collections::HashSet,
+    process::{Command, Stdio},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+use tui_additions::framework::Framework;
+
+/// starts a background refresh of the subscriptions feed when the refresh interval has passed,
+/// and handles the result of a finished one
+pub fn refresh_subscriptions(framework: &mut Framework) {
+    let interval = framework
+        .data
+        .global
+        .get::<MainConfig>()
+        .unwrap()
+        .refresh_interval;
+    if interval == 0 {
+        return;
+    }
+
+    let refresher = framework.data.global.get_mut::<Refresher>().unwrap();
+
+    // a refresh is still running
+    if let Some(pending) = &refresher.pending {
+        let res = match pending.lock().unwrap().try_recv() {
+            Ok(res) => res,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => Err(String::from("Refresher stopped")),
+        };
+        refresher.pending = None;
+
+        // errors are not shown, as they would interrupt whatever the user is doing
+        if let Ok(mut entries) = res {
+            let filters = framework.data.global.get::<FiltersConfig>().unwrap();
+            entries.retain(|entry| filters.allows(&entry.item));
+            handle_new_entries(framework, &entries);
+        }
+        return;
+    }
+
+    if refresher.last_refresh.elapsed() < Duration::from_secs(interval) {
+        return;
+    }
+
+    let channel_ids = framework
+        .data
+        .global
+        .get::<Subscriptions>()
+        .unwrap()
+        .0
+        .iter()
+        .filter_map(|channel| channel.id().map(String::from))
+        .collect::<Vec<_>>();
+    let mainconfig = framework.data.global.get::<MainConfig>().unwrap();
+    let client = framework
+        .data
+        .global
+        .get::<InvidiousClient>()
+        .unwrap()
+        .0
+        .clone();
+    let base = match framework.data.global.get::<Status>().unwrap().provider {
+        Provider::YouTube => String::from("https://www.youtube.com"),
+        Provider::Invidious => mainconfig.invidious_instance.clone(),
+    };
+    let use_feeds = mainconfig.use_channel_feeds;
+    let image_index = mainconfig.image_index;
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(
+            subscriptions_feed(&channel_ids, &client, &base, use_feeds, image_index)
+                .map_err(|e| e.to_string()),
+        );
+    });
+
+    let refresher = framework.data.global.get_mut::<Refresher>().unwrap();
+    refresher.last_refresh = Instant::now();
+    refresher.pending = Some(Arc::new(Mutex::new(receiver)));
+}
+
+/// update unread videos, then notify the user about videos that were not unread before
+fn handle_new_entries(framework: &mut Framework, entries: &[FeedEntry]) {
+    let last_seen = framework.data.global.get_mut::<LastSeen>().unwrap();
+    let previous = last_seen.unread.keys().cloned().collect::<HashSet<_>>();
+    last_seen.update(entries);
+    let _ = last_seen.save();
+    framework
+        .data
+        .global
+        .get_mut::<LabelsRevision>()
+        .unwrap()
+        .bump();
+    let last_seen = framework.data.global.get::<LastSeen>().unwrap();
+
+    let new = entries
+        .iter()
+        .filter_map(|entry| match &entry.item {
+            Item::MiniVideo(video)
+                if last_seen.is_unread(&video.id) && !previous.contains(&video.id) =>
+            {
+                Some(video.clone())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if new.is_empty() {
+        return;
+    }
+
+    let mainconfig = framework.data.global.get::<MainConfig>().unwrap();
+    if !mainconfig.new_video_hook.is_empty() {
+        for video in new.iter() {
+            let _ = new_video_hook(&mainconfig.shell, &mainconfig.new_video_hook, video).spawn();
+        }
+    }
+
+    *framework.data.global.get_mut::<Message>().unwrap() = Message::Success(if new.len() == 1 {
+        format!("New video from {}: {}", new[0].channel, new[0].title)
+    } else {
+        format!("{} new videos from subscriptions", new.len())
+    });
+    framework
+        .data
+        .state
+        .get_mut::<Tasks>()
+        .unwrap()
+        .priority
+        .push(Task::RenderAll);
+}
+
+/// info of the video is passed as envs of the hook instead of being pasted into the command, so
+/// that titles cannot run commands, and so that they are not replaced by the envs of the page
+/// currently open
+fn new_video_hook(shell: &str, hook: &str, video: &MiniVideoItem) -> Command {
+    let mut command = Command::new(shell);
+    command
+        .args(["-c", hook])
+        .envs([
+            ("title", &video.title),
+            ("id", &video.id),
+            ("channel", &video.channel),
+            ("channel-id", &video.channel_id),
+            // shells drop envs with a dash in the name
+            ("channel_id", &video.channel_id),
+        ])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs, process};
+
+    #[test]
+    fn hook_does_not_run_titles() {
+        let dir = env::temp_dir().join(format!("youtube-tui-hook-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let output = dir.join("output");
+        let pwned = dir.join("pwned");
+
+        let title = format!(
+            "'; touch {0}; echo \"$(touch {0})\" `touch {0}` && ${{HOME}} | > <",
+            pwned.display()
+        );
+        let video = MiniVideoItem {
+            title: title.clone(),
+            id: String::from("dQw4w9WgXcQ"),
+            thumbnail_url: String::new(),
+            length: String::new(),
+            views: None,
+            channel: format!("$(touch {})", pwned.display()),
+            channel_id: String::from("UCuAXFkgsw1L7xaCfnd5JJOw"),
+            published: None,
+            description: None,
+        };
+
+        // set by the last opened video
+        env::set_var("title", "previous video");
+
+        let hook = format!(
+            "printf '%s\\n' \"$title\" \"${{title}}\" \"$id\" \"$channel\" \"$channel_id\" > '{}'",
+            output.display()
+        );
+        let status = new_video_hook("sh", &hook, &video).status().unwrap();
+        assert!(status.success());
+
+        assert_eq!(
+            fs::read_to_string(&output).unwrap(),
+            format!(
+                "{title}\n{title}\ndQw4w9WgXcQ\n{}\nUCuAXFkgsw1L7xaCfnd5JJOw\n",
+                video.channel
+            )
+        );
+        assert!(!pwned.exists());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
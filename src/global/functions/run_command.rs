@@ -1,17 +1,15 @@
 use crate::{
     config::*,
     global::{functions::*, structs::*, traits::*},
-    load_configs,
+    help_message,
 };
-use home::home_dir;
 use std::{
     env,
     error::Error,
     fs,
-    io::Stdout,
     process::{Command, Stdio},
 };
-use tui::{backend::CrosstermBackend, Terminal};
+use tui::Terminal;
 use tui_additions::framework::Framework;
 
 /// runs text command - command from the command line (not TUI) which response is just a string
@@ -21,7 +19,7 @@ pub fn text_command(command: &str) -> Option<String> {
         .collect::<Vec<_>>()
         .as_slice()
     {
-        ["help"] => Some(String::from(HELP_MSG)),
+        ["help"] => Some(help_message()),
         ["version"] => Some(format!(
             "{} {}",
             env!("CARGO_PKG_NAME"),
@@ -31,10 +29,10 @@ pub fn text_command(command: &str) -> Option<String> {
     }
 }
 
-pub fn run_command(
+pub fn run_command<B: FrameworkBackend>(
     command: &str,
     framework: &mut Framework,
-    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    terminal: &mut Terminal<B>,
 ) {
     command.split(";;").into_iter().for_each(|single_command| {
         run_single_command(
@@ -49,10 +47,10 @@ pub fn run_command(
 }
 
 /// runs a command in the TUI, returns true if its a loadpage command, false if not
-pub fn run_single_command(
+pub fn run_single_command<B: FrameworkBackend>(
     command: &[&str],
     framework: &mut Framework,
-    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    terminal: &mut Terminal<B>,
 ) {
     // match a command splitted by space to a bunch of avaliable commands
     match command {
@@ -60,9 +58,7 @@ pub fn run_single_command(
         ["bookmark", id] => {
             match (|| -> Result<Item, Box<dyn Error>> {
                 Ok(serde_json::from_str(&fs::read_to_string(
-                    home_dir()
-                        .unwrap()
-                        .join(format!(".cache/youtube-tui/info/{id}.json")),
+                    cache_dir().join(format!("info/{id}.json")),
                 )?)?)
             })() {
                 Ok(item) => {
@@ -100,6 +96,235 @@ pub fn run_single_command(
                 run_single_command(&["bookmark", id], framework, terminal);
             }
         }
+        ["subscribe", identifier] => {
+            let server = &framework
+                .data
+                .global
+                .get::<InvidiousClient>()
+                .unwrap()
+                .0
+                .server;
+            let id = match from_channel_url(server, identifier) {
+                Ok(id) => id,
+                Err(e) => {
+                    *framework.data.global.get_mut::<Message>().unwrap() = Message::Error(e);
+                    return;
+                }
+            };
+            let image_index = framework
+                .data
+                .global
+                .get::<MainConfig>()
+                .unwrap()
+                .image_index;
+
+            match framework
+                .data
+                .global
+                .get::<InvidiousClient>()
+                .unwrap()
+                .0
+                .channel(&id, None)
+            {
+                Ok(channel) => {
+                    let channel = Item::from_full_channel(channel, image_index);
+                    let message = format!("Subscribed to {channel}");
+                    let subscriptions = framework.data.global.get_mut::<Subscriptions>().unwrap();
+                    let _ = subscriptions.push(channel, None);
+                    let _ = subscriptions.save();
+                    *framework.data.global.get_mut::<Message>().unwrap() = Message::Success(message)
+                }
+                Err(e) => {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Error(format!("Unknown channel: {e}"))
+                }
+            }
+        }
+        ["unsubscribe", id] => {
+            let subscriptions = framework.data.global.get_mut::<Subscriptions>().unwrap();
+
+            if subscriptions.remove(id) {
+                let _ = subscriptions.save();
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Success(String::from("Unsubscribed"))
+            } else {
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Error(String::from("Not subscribed to a channel with that ID"))
+            }
+        }
+        ["togglesub", id] => {
+            let subscriptions = framework.data.global.get_mut::<Subscriptions>().unwrap();
+            if subscriptions.remove(id) {
+                let _ = subscriptions.save();
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Success(String::from("Unsubscribed"))
+            } else {
+                run_single_command(&["subscribe", id], framework, terminal);
+            }
+        }
+        ["markread"] => match env::var("hover-id") {
+            Ok(id) => run_single_command(&["markread", &id], framework, terminal),
+            Err(_) => {
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Message(String::from("Usage: `markread [id/all]`"))
+            }
+        },
+        // in a saved search feed, results of the saved search are marked as read instead
+        ["markread", "all"] => {
+            if let Page::MainMenu(MainMenuPage::SavedSearch(name)) =
+                framework.data.state.get::<Page>().unwrap()
+            {
+                let saved_searches = framework.data.global.get_mut::<SavedSearches>().unwrap();
+                saved_searches.mark_all_read(name);
+                let _ = saved_searches.save();
+            } else {
+                let last_seen = framework.data.global.get_mut::<LastSeen>().unwrap();
+                last_seen.mark_all_read();
+                let _ = last_seen.save();
+            }
+            framework
+                .data
+                .global
+                .get_mut::<LabelsRevision>()
+                .unwrap()
+                .bump();
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Success(String::from("All videos marked as read"));
+            framework
+                .data
+                .state
+                .get_mut::<Tasks>()
+                .unwrap()
+                .priority
+                .push(Task::RenderAll);
+        }
+        ["markread", id] => {
+            let marked = if let Page::MainMenu(MainMenuPage::SavedSearch(name)) =
+                framework.data.state.get::<Page>().unwrap()
+            {
+                let saved_searches = framework.data.global.get_mut::<SavedSearches>().unwrap();
+                let marked = saved_searches.mark_read(name, id);
+                if marked {
+                    let _ = saved_searches.save();
+                }
+                marked
+            } else {
+                let last_seen = framework.data.global.get_mut::<LastSeen>().unwrap();
+                let marked = last_seen.mark_read(id);
+                if marked {
+                    let _ = last_seen.save();
+                }
+                marked
+            };
+            if marked {
+                framework
+                    .data
+                    .global
+                    .get_mut::<LabelsRevision>()
+                    .unwrap()
+                    .bump();
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Success(String::from("Marked as read"));
+                framework
+                    .data
+                    .state
+                    .get_mut::<Tasks>()
+                    .unwrap()
+                    .priority
+                    .push(Task::RenderAll);
+            } else {
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Error(String::from("No unread video with that ID found"))
+            }
+        }
+        ["block", "channel", id] => {
+            let filters = framework.data.global.get_mut::<FiltersConfig>().unwrap();
+            if filters.blocked_channels.iter().any(|blocked| blocked == id) {
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Error(String::from("Channel is already blocked"));
+                return;
+            }
+
+            filters.blocked_channels.push(id.to_string());
+            let _ = filters.save();
+            framework
+                .data
+                .global
+                .get_mut::<ConfigWatcher>()
+                .unwrap()
+                .refresh();
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Success(String::from("Channel blocked"));
+            framework
+                .data
+                .state
+                .get_mut::<Tasks>()
+                .unwrap()
+                .priority
+                .push(Task::Reload);
+        }
+        ["unblock", "channel", id] => {
+            let filters = framework.data.global.get_mut::<FiltersConfig>().unwrap();
+            let original_len = filters.blocked_channels.len();
+            filters.blocked_channels.retain(|blocked| blocked != id);
+
+            if filters.blocked_channels.len() == original_len {
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Error(String::from("Channel is not blocked"));
+                return;
+            }
+
+            let _ = filters.save();
+            framework
+                .data
+                .global
+                .get_mut::<ConfigWatcher>()
+                .unwrap()
+                .refresh();
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Success(String::from("Channel unblocked"));
+            framework
+                .data
+                .state
+                .get_mut::<Tasks>()
+                .unwrap()
+                .priority
+                .push(Task::Reload);
+        }
+        ["block", ..] | ["unblock", ..] => {
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Message(format!("Usage: `{} channel [id]`", command[0]));
+        }
+        ["togglewatched"] => {
+            let page = framework.data.state.get::<Page>().unwrap();
+            match framework
+                .data
+                .global
+                .get_mut::<HideWatched>()
+                .unwrap()
+                .toggle(page)
+            {
+                Some(hidden) => {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Success(String::from(if hidden {
+                            "Watched videos are hidden in this page"
+                        } else {
+                            "Watched videos are shown in this page"
+                        }));
+                    framework
+                        .data
+                        .state
+                        .get_mut::<Tasks>()
+                        .unwrap()
+                        .priority
+                        .push(Task::Reload);
+                }
+                None => {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Error(String::from("Watched videos cannot be hidden in this page"))
+                }
+            }
+        }
         ["help"] | ["h"] => {
             *framework.data.global.get_mut::<Message>().unwrap() = Message::Message(String::from(
                 "Avaliable commands can be viewed by running `youtube-tui help` in terminal",
@@ -114,6 +339,59 @@ pub fn run_single_command(
                 Message::Success(format!("Provider updated to {}", status.provider.as_str()));
             update_provider(&mut framework.data);
         }
+        ["region"] => {
+            *framework.data.global.get_mut::<Message>().unwrap() = Message::Message(format!(
+                "Current region is {}, usage: `region {{code}}`",
+                framework.data.global.get::<MainConfig>().unwrap().region
+            ));
+        }
+        // sets the region of trending videos
+        ["region", code] => {
+            if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+                *framework.data.global.get_mut::<Message>().unwrap() = Message::Error(format!(
+                    "`{code}` is not a valid region, use a two letter country code like `US`"
+                ));
+                return;
+            }
+
+            framework
+                .data
+                .global
+                .get_mut::<MainConfig>()
+                .unwrap()
+                .region = code.to_ascii_uppercase();
+            // saves the config as it is on disk, so that overrides from command line options
+            // are not written to it
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                match MainConfig::load(WriteConfig::Dont).and_then(|mut mainconfig| {
+                    mainconfig.region = code.to_ascii_uppercase();
+                    mainconfig.save()
+                }) {
+                    Ok(()) => {
+                        Message::Success(format!("Region set to {}", code.to_ascii_uppercase()))
+                    }
+                    Err(e) => Message::Error(format!("Region set but could not be saved: {e}")),
+                };
+            framework
+                .data
+                .global
+                .get_mut::<ConfigWatcher>()
+                .unwrap()
+                .refresh();
+            update_provider(&mut framework.data);
+
+            if let Page::MainMenu(MainMenuPage::Trending(_)) =
+                framework.data.state.get::<Page>().unwrap()
+            {
+                framework
+                    .data
+                    .state
+                    .get_mut::<Tasks>()
+                    .unwrap()
+                    .priority
+                    .push(Task::Reload);
+            }
+        }
         ["loadpage"] => {
             *framework.data.global.get_mut::<Message>().unwrap() =
                 Message::Message(String::from("Usage: `loadpage {page}`"));
@@ -122,8 +400,23 @@ pub fn run_single_command(
         ["loadpage", page, ..] => {
             let page = match *page {
                 "popular" => Some(Page::MainMenu(MainMenuPage::Popular)),
-                "trending" => Some(Page::MainMenu(MainMenuPage::Trending)),
+                "trending" => match command.get(2) {
+                    Some(tab) => match TrendingTab::from_name(tab) {
+                        Some(tab) => Some(Page::MainMenu(MainMenuPage::Trending(tab))),
+                        None => {
+                            *framework.data.global.get_mut::<Message>().unwrap() =
+                                Message::Error(format!(
+                                    "Unknown trending tab `{tab}`, avaliable tabs are: now, music, gaming, news, movies"
+                                ));
+                            return;
+                        }
+                    },
+                    None => Some(Page::MainMenu(MainMenuPage::Trending(
+                        TrendingTab::default(),
+                    ))),
+                },
                 "watchhistory" => Some(Page::MainMenu(MainMenuPage::History)),
+                "searchhistory" => Some(Page::MainMenu(MainMenuPage::SearchHistory)),
                 "subscriptions" => Some(Page::MainMenu(MainMenuPage::Subscriptions)),
                 "library" => Some(Page::MainMenu(MainMenuPage::Library)),
                 "channel" => {
@@ -133,10 +426,19 @@ pub fn run_single_command(
                         return;
                     }
 
-                    match from_channel_url(command[2]) {
+                    let server = &framework
+                        .data
+                        .global
+                        .get::<InvidiousClient>()
+                        .unwrap()
+                        .0
+                        .server;
+                    match from_channel_url(server, command[2]) {
                         Ok(id) => Some(Page::ChannelDisplay(ChannelDisplayPage {
                             id,
                             r#type: ChannelDisplayPageType::Main,
+                            sort: ChannelSort::default(),
+                            query: String::new(),
                         })),
                         Err(e) => {
                             *framework.data.global.get_mut::<Message>().unwrap() =
@@ -153,7 +455,7 @@ pub fn run_single_command(
                     }
 
                     match from_video_url(command[2]) {
-                        Ok(id) => Some(Page::SingleItem(SingleItemPage::Video(id))),
+                        Ok((id, start)) => Some(Page::SingleItem(SingleItemPage::Video(id, start))),
                         Err(e) => {
                             *framework.data.global.get_mut::<Message>().unwrap() =
                                 Message::Error(e);
@@ -177,6 +479,28 @@ pub fn run_single_command(
                         }
                     }
                 }
+                "feed" => {
+                    if command.len() == 2 {
+                        *framework.data.global.get_mut::<Message>().unwrap() =
+                            Message::Message(String::from("Usage: `loadpage feed {name}`"));
+                        return;
+                    }
+
+                    let name = command[2..].join(" ");
+                    if framework
+                        .data
+                        .global
+                        .get::<SavedSearches>()
+                        .unwrap()
+                        .get(&name)
+                        .is_none()
+                    {
+                        *framework.data.global.get_mut::<Message>().unwrap() =
+                            Message::Error(format!("No saved search named `{name}`"));
+                        return;
+                    }
+                    Some(Page::MainMenu(MainMenuPage::SavedSearch(name)))
+                }
                 "search" => {
                     if command.len() == 2 {
                         *framework.data.global.get_mut::<Message>().unwrap() =
@@ -211,7 +535,69 @@ pub fn run_single_command(
         // redirects to the relevant `loadpage` command
         ["popular"] => run_single_command(&["loadpage", "popular"], framework, terminal),
         ["trending"] => run_single_command(&["loadpage", "trending"], framework, terminal),
+        ["trending", tab] => {
+            run_single_command(&["loadpage", "trending", tab], framework, terminal)
+        }
         ["watchhistory"] => run_single_command(&["loadpage", "watchhistory"], framework, terminal),
+        ["searchhistory"] => {
+            run_single_command(&["loadpage", "searchhistory"], framework, terminal)
+        }
+        ["savesearch"] => {
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Message(String::from("Usage: `savesearch [name]`"));
+        }
+        // saves the search in the current search page
+        ["savesearch", ..] => {
+            let search = match framework.data.state.get::<Page>().unwrap() {
+                Page::Search(search) => search.clone(),
+                _ => {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Error(String::from("Only searches can be saved"));
+                    return;
+                }
+            };
+
+            let name = command[1..].join(" ");
+            let saved_searches = framework.data.global.get_mut::<SavedSearches>().unwrap();
+            let replaced = saved_searches.insert(name.clone(), search);
+            *framework.data.global.get_mut::<Message>().unwrap() = match saved_searches.save() {
+                Ok(()) if replaced => Message::Success(format!("Saved search `{name}` replaced")),
+                Ok(()) => Message::Success(format!("Search saved as `{name}`")),
+                Err(e) => Message::Error(e.to_string()),
+            };
+        }
+        // removes the saved search of the current feed page if no name is given
+        ["unsavesearch", ..] => {
+            let name = match (command.len(), framework.data.state.get::<Page>().unwrap()) {
+                (1, Page::MainMenu(MainMenuPage::SavedSearch(name))) => name.clone(),
+                (1, _) => {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Message(String::from("Usage: `unsavesearch [name]`"));
+                    return;
+                }
+                _ => command[1..].join(" "),
+            };
+
+            let saved_searches = framework.data.global.get_mut::<SavedSearches>().unwrap();
+            if !saved_searches.remove(&name) {
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Error(format!("No saved search named `{name}`"));
+                return;
+            }
+            *framework.data.global.get_mut::<Message>().unwrap() = match saved_searches.save() {
+                Ok(()) => Message::Success(format!("Saved search `{name}` removed")),
+                Err(e) => Message::Error(e.to_string()),
+            };
+        }
+        ["feed", ..] => run_single_command(&[&["loadpage"], command].concat(), framework, terminal),
+        ["searchhistory", "clear"] => {
+            let search_history = framework.data.global.get_mut::<SearchHistory>().unwrap();
+            search_history.clear();
+            *framework.data.global.get_mut::<Message>().unwrap() = match search_history.save() {
+                Ok(()) => Message::Success(String::from("Search history cleared")),
+                Err(e) => Message::Error(e.to_string()),
+            };
+        }
         ["subscriptions"] => {
             run_single_command(&["loadpage", "subscriptions"], framework, terminal)
         }
@@ -225,6 +611,93 @@ pub fn run_single_command(
             framework,
             terminal,
         ),
+        // cycles through sort orders if none is given
+        ["channel", "sort", sort @ ..] if sort.len() <= 1 => {
+            let page = match framework.data.state.get::<Page>().unwrap() {
+                Page::ChannelDisplay(page) => page,
+                _ => {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Error(String::from("Not in a channel page"));
+                    return;
+                }
+            };
+
+            let sort_arg = sort.first();
+            let sort = match sort_arg {
+                Some(sort) => match ChannelSort::from_name(sort) {
+                    Some(sort) => sort,
+                    None => {
+                        *framework.data.global.get_mut::<Message>().unwrap() = Message::Message(
+                            String::from("Usage: `channel sort (newest/popular/oldest)`"),
+                        );
+                        return;
+                    }
+                },
+                None => page.sort.next(),
+            };
+
+            // stays in the shorts, streams or playlists tab if already in there
+            let r#type = match page.r#type {
+                ChannelDisplayPageType::Shorts
+                | ChannelDisplayPageType::Streams
+                | ChannelDisplayPageType::Playlists => page.r#type,
+                _ => ChannelDisplayPageType::Videos,
+            };
+            // playlists can only be sorted by date
+            let sort = match (r#type, sort) {
+                (ChannelDisplayPageType::Playlists, ChannelSort::Popular) if sort_arg.is_some() => {
+                    *framework.data.global.get_mut::<Message>().unwrap() = Message::Error(
+                        String::from("Playlists can only be sorted by newest or oldest"),
+                    );
+                    return;
+                }
+                (ChannelDisplayPageType::Playlists, ChannelSort::Popular) => sort.next(),
+                _ => sort,
+            };
+            let page = Page::ChannelDisplay(ChannelDisplayPage {
+                r#type,
+                sort,
+                ..page.clone()
+            });
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Success(format!("Sorted by {}", sort.as_str()));
+            framework
+                .data
+                .state
+                .get_mut::<Tasks>()
+                .unwrap()
+                .priority
+                .push(Task::LoadPage(page));
+        }
+        ["channel", "search", query @ ..] => {
+            let page = match framework.data.state.get::<Page>().unwrap() {
+                Page::ChannelDisplay(page) => page,
+                _ => {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Error(String::from("Not in a channel page"));
+                    return;
+                }
+            };
+
+            if query.is_empty() {
+                *framework.data.global.get_mut::<Message>().unwrap() =
+                    Message::Message(String::from("Usage: `channel search {query}`"));
+                return;
+            }
+
+            let page = Page::ChannelDisplay(ChannelDisplayPage {
+                r#type: ChannelDisplayPageType::Search,
+                query: query.join(" "),
+                ..page.clone()
+            });
+            framework
+                .data
+                .state
+                .get_mut::<Tasks>()
+                .unwrap()
+                .priority
+                .push(Task::LoadPage(page));
+        }
         ["channel"] => run_single_command(&["loadpage", "channel"], framework, terminal),
         ["channel", identifier] => {
             run_single_command(&["loadpage", "channel", *identifier], framework, terminal)
@@ -237,6 +710,58 @@ pub fn run_single_command(
         ["playlist", identifier] => {
             run_single_command(&["loadpage", "playlist", *identifier], framework, terminal)
         }
+        ["open"] => {
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Message(String::from("Usage: `open {url/id}`"));
+        }
+        // loads the page of whatever the url points to
+        ["open", identifier] => {
+            let page = match parse_url(identifier) {
+                Ok(UrlTarget::Video(id, start)) => {
+                    Page::SingleItem(SingleItemPage::Video(id, start))
+                }
+                Ok(UrlTarget::Playlist(id)) => Page::SingleItem(SingleItemPage::Playlist(id)),
+                Ok(UrlTarget::Channel(_) | UrlTarget::ChannelPath(_)) => {
+                    let server = &framework
+                        .data
+                        .global
+                        .get::<InvidiousClient>()
+                        .unwrap()
+                        .0
+                        .server;
+                    match from_channel_url(server, identifier) {
+                        Ok(id) => Page::ChannelDisplay(ChannelDisplayPage {
+                            id,
+                            r#type: ChannelDisplayPageType::Main,
+                            sort: ChannelSort::default(),
+                            query: String::new(),
+                        }),
+                        Err(e) => {
+                            *framework.data.global.get_mut::<Message>().unwrap() =
+                                Message::Error(e);
+                            return;
+                        }
+                    }
+                }
+                Err(e) => {
+                    *framework.data.global.get_mut::<Message>().unwrap() = Message::Error(e);
+                    return;
+                }
+            };
+
+            let tasks = framework.data.state.get_mut::<Tasks>().unwrap();
+            match (&page, from_playlist_url(identifier)) {
+                // a video in a playlist is loaded on top of the playlist, so the playlist is one
+                // `back` away
+                (Page::SingleItem(SingleItemPage::Video(_, _)), Ok(playlist)) => {
+                    tasks.priority.push(Task::LoadPage(Page::SingleItem(
+                        SingleItemPage::Playlist(playlist),
+                    )));
+                    tasks.last.push(Task::LoadPage(page));
+                }
+                _ => tasks.priority.push(Task::LoadPage(page)),
+            }
+        }
         ["history"] => {
             *framework.data.global.get_mut::<Message>().unwrap() =
                 Message::Message(String::from("Usage: `history [back/clear]`"))
@@ -287,11 +812,40 @@ pub fn run_single_command(
         }
         ["reload", "config"] | ["reload", "configs"] | ["r", "config"] | ["r", "configs"] => {
             *framework.data.global.get_mut::<Message>().unwrap() =
-                match load_configs(&mut framework.split_clean().0) {
+                match reload_configs(framework, true) {
                     Ok(()) => Message::Success(String::from("Config files have been reloaded")),
                     Err(e) => Message::Error(e.to_string()),
                 };
         }
+        ["profile"] => {
+            let profiles = profiles();
+            *framework.data.global.get_mut::<Message>().unwrap() = Message::Message(format!(
+                "Current profile is {}, available profiles: {}, usage: `profile {{name}}`",
+                profile().unwrap_or(String::from("default")),
+                if profiles.is_empty() {
+                    String::from("none")
+                } else {
+                    profiles.join(", ")
+                }
+            ));
+        }
+        // switches to another profile, `default` uses the config files without a profile
+        ["profile", name] => {
+            let previous = profile();
+            let name = (*name != "default").then(|| name.to_string());
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                match set_profile(name.clone()).and_then(|_| reload_configs(framework, false)) {
+                    Ok(()) => Message::Success(format!(
+                        "Switched to profile {}",
+                        name.unwrap_or(String::from("default"))
+                    )),
+                    Err(e) => {
+                        // the configs of the previous profile are still loaded
+                        let _ = set_profile(previous);
+                        Message::Error(e.to_string())
+                    }
+                };
+        }
         ["q"] | ["quit"] | ["x"] | ["exit"] => {
             framework.data.global.get_mut::<Status>().unwrap().exit = true;
         }
@@ -382,56 +936,3 @@ const HELLO_WORLDS: &[&str] = &[
     "print *, \"Hello World\"",
     "<h1>Hello World<\\h1>",
 ];
-
-const HELP_MSG: &str = "\x1b[32mYouTube TUI commands\x1b[0m
-
-\x1b[37mfor more visit https://siriusmart.github.io/youtube-tui/commands.html\x1b[0m
-
-\x1b[91mUSAGE:\x1b[0m
-    youtube-tui (command)
-
-\x1b[91mINFORMATIONAL:\x1b[0m
-    \x1b[33mhelp\x1b[0m                            Display this message
-    \x1b[33mversion\x1b[0m                         Print version info and exit
-
-\x1b[91mLOADPAGE:
-\x1b[37mloadpage [page] loads the corresponding page\x1b[0m
-    \x1b[33mloadpage popular\x1b[0m                Loads the popular videos page
-    \x1b[33mloadpage trending\x1b[0m               Loads the trending videos page
-    \x1b[33mloadpage watchhistory\x1b[0m           Loads the watch history page
-    \x1b[33mloadpage subscriptions\x1b[0m          Loads the subscriptions page
-    \x1b[33mloadpage bookmarks\x1b[0m              Loads the bookmarks page
-    \x1b[33mloadpage library\x1b[0m                Loads the library (saved items) page
-    \x1b[33mloadpage search [query]\x1b[0m         Loads the search page with the given query
-    \x1b[33mloadpage video [identifier]\x1b[0m     Loads the video item page
-    \x1b[33mloadpage playlist [identifier]\x1b[0m  Loads the playlist item page
-    \x1b[33mloadpage channel [identifier]\x1b[0m   Loads the channel item page
-
-\x1b[91mHISTORY:\x1b[0m
-    \x1b[33mhistory back\x1b[0m                    Revert back to previous state
-    \x1b[33mhistory clear\x1b[0m                   Clear all previously saved states, making the current state the original
-
-\x1b[91mUTILITY:\x1b[0m
-    \x1b[33mreload\x1b[0m                          Reloads the current page
-    \x1b[33mreload configs\x1b[0m                  Reload all config files
-    \x1b[33mflush\x1b[0m                           Run all tasks in queue immediately
-    \x1b[33mquit\x1b[0m                            Immediately exit
-    \x1b[33mrun [command]\x1b[0m                   Runs a system command (e.g. `run rm -rf / --no-preserve-root`)
-    \x1b[33mparrun [command]\x1b[0m                Runs a system command non blocking (e.g. `run firefox example.com`)
-    \x1b[33mcopy [text]\x1b[0m                     Copies text to clipboard
-
-\x1b[91mLIBRARY:\x1b[0m
-    \x1b[33mbookmark [id]\x1b[0m                   Bookmark item with ID (item must be already loaded)
-    \x1b[33munmark [id]\x1b[0m                     Remove bookmark item with ID
-    \x1b[33mtogglemark [id]\x1b[0m                 Toggle bookmark status
-
-\x1b[91mALT:\x1b[0m
-\x1b[37malts links back to the original command\x1b[30m
-    \x1b[33m[page] (additional options)\x1b[0m     `loadpage [page]`
-    \x1b[33mback\x1b[0m                            `history back`
-    \x1b[33mr\x1b[0m                               `reload`
-    \x1b[33mreload/r config/configs\x1b[0m         `reload configs`
-    \x1b[33mq, exit, x\x1b[0m                      `quit`
-    \x1b[33mcp [text]\x1b[0m                       `copy [text]`
-
-\x1b[37mOnly load page and informational commands can be used from command line, the rest can only be used in (`:`) command mode inside the TUI.\x1b[0m";
@@ -0,0 +1,9 @@
+use std::sync::OnceLock;
+use tokio::runtime::Runtime;
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// the async runtime shared by everything that fetches concurrently, created on first use
+pub fn runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| Runtime::new().expect("Cannot start the async runtime"))
+}
@@ -0,0 +1,91 @@
+use serde::Deserialize;
+use std::{
+    error::Error,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// how long to wait after the last keystroke before fetching suggestions
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// suggestions fetched in the background, taken by the search bar on render
+pub type SuggestionsSlot = Arc<Mutex<Option<Vec<String>>>>;
+
+#[derive(Deserialize)]
+struct Suggestions {
+    #[serde(default)]
+    suggestions: Vec<String>,
+}
+
+/// fetch search suggestions for the query from the invidious api
+pub fn search_suggestions(server: &str, query: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let url = reqwest::Url::parse_with_params(
+        &format!("{server}/api/v1/search/suggestions"),
+        &[("q", query)],
+    )?;
+    let suggestions =
+        serde_json::from_str::<Suggestions>(&reqwest::blocking::get(url)?.text()?)?.suggestions;
+
+    // some instances returns suggestions with html entities
+    Ok(suggestions
+        .into_iter()
+        .map(|suggestion| {
+            suggestion
+                .replace("&#39;", "'")
+                .replace("&quot;", "\"")
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&amp;", "&")
+        })
+        .collect())
+}
+
+/// a query to fetch suggestions for, sent to the suggestions worker
+pub struct SuggestionsRequest {
+    pub server: String,
+    pub query: String,
+    /// generation of the search bar when the query is sent
+    pub generation: usize,
+}
+
+/// starts a thread which fetches suggestions once the user stopped typing, only the latest
+/// request is fetched, and the result is only put into `slot` if `generation` is still the one of
+/// the request, so that suggestions for outdated input are dropped
+// the thread stops when all senders are dropped
+pub fn suggestions_worker(
+    generation: Arc<AtomicUsize>,
+    slot: SuggestionsSlot,
+) -> mpsc::Sender<SuggestionsRequest> {
+    let (sender, receiver) = mpsc::channel::<SuggestionsRequest>();
+
+    thread::spawn(move || {
+        while let Ok(mut request) = receiver.recv() {
+            // newer requests replace the current one until none is sent for `DEBOUNCE`
+            loop {
+                match receiver.recv_timeout(DEBOUNCE) {
+                    Ok(newer) => request = newer,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+
+            if generation.load(Ordering::SeqCst) != request.generation {
+                continue;
+            }
+
+            // failing to get suggestions should not interrupt typing, so errors are ignored
+            let suggestions =
+                search_suggestions(&request.server, &request.query).unwrap_or_default();
+            if generation.load(Ordering::SeqCst) == request.generation {
+                *slot.lock().unwrap() = Some(suggestions);
+            }
+        }
+    });
+
+    sender
+}
@@ -1,6 +1,13 @@
 use crate::{config::MainConfig, global::functions::download_all_images, global::structs::Item};
 use invidious::reqwest::blocking::Client;
-use std::error::Error;
+use std::{
+    error::Error,
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+/// a page of playlist videos loaded in the background
+pub type PlaylistBatch = Result<Vec<Item>, String>;
 
 pub fn load_playlist(
     client: &Client,
@@ -21,6 +28,69 @@ pub fn load_playlist(
     Ok(playlist)
 }
 
+/// loads the rest of a playlist page by page in a background thread, each page of videos is sent
+/// through the returned receiver, which disconnects when the playlist is fully loaded
+///
+/// `loaded` is the number of videos already loaded, videos before that index are skipped
+pub fn load_playlist_rest(
+    client: &Client,
+    id: &str,
+    loaded: usize,
+    start_page: usize,
+    video_count: usize,
+    mainconfig: &MainConfig,
+) -> Receiver<PlaylistBatch> {
+    let (sender, receiver) = mpsc::channel();
+    let client = client.clone();
+    let id = id.to_string();
+    let image_index = mainconfig.image_index;
+    let download_images = mainconfig.images.display();
+
+    thread::spawn(move || {
+        let mut next_index = loaded;
+        // pages have 100 videos, the page count is capped in case the instance keeps returning
+        // videos
+        for page in start_page..=video_count / 100 + 1 {
+            let videos = match client.playlist(&id, Some(&format!("page={page}"))) {
+                Ok(playlist) => playlist.videos,
+                Err(e) => {
+                    let _ = sender.send(Err(e.to_string()));
+                    return;
+                }
+            };
+
+            if videos.is_empty() {
+                return;
+            }
+
+            // pages may overlap with what is already loaded
+            let videos = videos
+                .into_iter()
+                .filter(|video| video.index as usize >= next_index)
+                .collect::<Vec<_>>();
+            // some instances keep returning the last page instead of an empty one
+            next_index = match videos.last() {
+                Some(video) => video.index as usize + 1,
+                None => return,
+            };
+
+            let videos = videos
+                .into_iter()
+                .map(|video| Item::from_playlist_item(video, image_index))
+                .collect::<Vec<_>>();
+            if download_images {
+                download_all_images(videos.iter().map(|item| item.into()).collect());
+            }
+
+            if sender.send(Ok(videos)).is_err() {
+                return;
+            }
+        }
+    });
+
+    receiver
+}
+
 pub fn load_video(
     client: &Client,
     id: &str,
@@ -33,3 +103,46 @@ pub fn load_video(
 
     Ok(video)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Images, headless::serve};
+    use std::fs;
+
+    fn mainconfig() -> MainConfig {
+        MainConfig {
+            images: Images::None,
+            ..Default::default()
+        }
+    }
+
+    fn load_all(loaded: usize, video_count: usize) -> Vec<PlaylistBatch> {
+        let body = fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/playlist.json"
+        ))
+        .unwrap();
+        let client = Client::new(serve(body));
+        load_playlist_rest(&client, "PLtest", loaded, 2, video_count, &mainconfig())
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn stops_on_repeated_pages() {
+        // the instance returns the same page for every page number
+        let batches = load_all(1, 500);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].as_ref().unwrap().len(), 2);
+
+        assert!(load_all(3, 500).is_empty());
+    }
+
+    #[test]
+    fn caps_page_count() {
+        // pages after the last one are not requested, so the same page is not loaded again
+        assert!(load_all(0, 50).is_empty());
+        assert_eq!(load_all(0, 150).len(), 1);
+    }
+}
@@ -1,5 +1,6 @@
 use std::env;
 use tui_additions::framework::FrameworkData;
+use url::form_urlencoded;
 
 use crate::{config::*, global::structs::*};
 
@@ -11,7 +12,12 @@ pub fn update_provider(data: &mut FrameworkData) {
     let page = data.state.get::<Page>().unwrap();
 
     let envs = match page {
-        Page::ChannelDisplay(ChannelDisplayPage { id, r#type }) => vec![(
+        Page::ChannelDisplay(ChannelDisplayPage {
+            id,
+            r#type,
+            sort,
+            query,
+        }) => vec![(
             String::from("url"),
             format!(
                 "{}/channel/{id}{}",
@@ -19,10 +25,44 @@ pub fn update_provider(data: &mut FrameworkData) {
                     Provider::YouTube => "https://youtube.com",
                     Provider::Invidious => &mainconfig.invidious_instance,
                 },
-                match r#type {
-                    ChannelDisplayPageType::Main => "",
-                    ChannelDisplayPageType::Videos => "/videos",
-                    ChannelDisplayPageType::Playlists => "/playlists",
+                match (r#type, status.provider) {
+                    (ChannelDisplayPageType::Main, _) => String::new(),
+                    (ChannelDisplayPageType::Videos, Provider::YouTube) => format!(
+                        "/videos?view=0&sort={}",
+                        match sort {
+                            ChannelSort::Newest => "dd",
+                            ChannelSort::Popular => "p",
+                            ChannelSort::Oldest => "da",
+                        }
+                    ),
+                    (ChannelDisplayPageType::Videos, Provider::Invidious) => {
+                        format!("/videos?sort_by={}", sort.as_str())
+                    }
+                    (ChannelDisplayPageType::Shorts, _) => String::from("/shorts"),
+                    (ChannelDisplayPageType::Streams, _) => String::from("/streams"),
+                    (ChannelDisplayPageType::Playlists, Provider::YouTube) => format!(
+                        "/playlists?view=1&sort={}",
+                        match sort {
+                            ChannelSort::Oldest => "da",
+                            _ => "dd",
+                        }
+                    ),
+                    (ChannelDisplayPageType::Playlists, Provider::Invidious) => {
+                        format!("/playlists?sort_by={}", sort.as_str())
+                    }
+                    (ChannelDisplayPageType::Community, _) => String::from("/community"),
+                    (ChannelDisplayPageType::Search, provider) => format!(
+                        "/search?{}",
+                        form_urlencoded::Serializer::new(String::new())
+                            .append_pair(
+                                match provider {
+                                    Provider::YouTube => "query",
+                                    Provider::Invidious => "q",
+                                },
+                                query
+                            )
+                            .finish()
+                    ),
                 }
             ),
         )],
@@ -48,13 +88,19 @@ pub fn update_provider(data: &mut FrameworkData) {
                 }
             },
         )],
-        Page::MainMenu(MainMenuPage::Trending) => vec![(
+        Page::MainMenu(MainMenuPage::Trending(tab)) => vec![(
             String::from("url"),
             match status.provider {
-                Provider::YouTube => String::from("https://www.youtube.com/feed/trending"),
-                Provider::Invidious => {
-                    format!("{}/feed/trending", mainconfig.invidious_instance)
-                }
+                // trending categories on YouTube have no readable urls
+                Provider::YouTube => format!(
+                    "https://www.youtube.com/feed/trending?gl={}",
+                    mainconfig.region
+                ),
+                Provider::Invidious => format!(
+                    "{}/feed/trending?{}",
+                    mainconfig.invidious_instance,
+                    tab.params(&mainconfig.region)
+                ),
             },
         )],
         Page::MainMenu(MainMenuPage::History) => vec![(
@@ -66,6 +112,32 @@ pub fn update_provider(data: &mut FrameworkData) {
                 }
             },
         )],
+        // neither YouTube nor Invidious has a page for past searches
+        Page::MainMenu(MainMenuPage::SearchHistory) => vec![(
+            String::from("url"),
+            match status.provider {
+                Provider::YouTube => String::from("https://www.youtube.com"),
+                Provider::Invidious => mainconfig.invidious_instance.clone(),
+            },
+        )],
+        Page::MainMenu(MainMenuPage::SavedSearch(name)) => vec![(
+            String::from("url"),
+            match (
+                data.global.get::<SavedSearches>().unwrap().get(name),
+                status.provider,
+            ) {
+                (Some(saved), Provider::YouTube) => {
+                    format!("https://youtube.com/results?{}", saved.search.to_string())
+                }
+                (Some(saved), Provider::Invidious) => format!(
+                    "{}/search?{}",
+                    mainconfig.invidious_instance,
+                    saved.search.to_string()
+                ),
+                (None, Provider::YouTube) => String::from("https://www.youtube.com"),
+                (None, Provider::Invidious) => mainconfig.invidious_instance.clone(),
+            },
+        )],
         Page::MainMenu(MainMenuPage::Subscriptions) => vec![(
             String::from("url"),
             match status.provider {
@@ -84,25 +156,37 @@ pub fn update_provider(data: &mut FrameworkData) {
                 }
             },
         )],
-        Page::SingleItem(SingleItemPage::Video(id)) => vec![
+        Page::SingleItem(SingleItemPage::Video(id, start)) => vec![
             (
                 String::from("url"),
-                match status.provider {
-                    Provider::Invidious => {
+                match (status.provider, start) {
+                    (Provider::Invidious, Some(start)) => {
+                        format!("{}/watch?v={}&t={start}", mainconfig.invidious_instance, id)
+                    }
+                    (Provider::Invidious, None) => {
                         format!("{}/watch?v={}", mainconfig.invidious_instance, id)
                     }
-                    Provider::YouTube => format!("https://youtu.be/{id}"),
+                    (Provider::YouTube, Some(start)) => format!("https://youtu.be/{id}?t={start}"),
+                    (Provider::YouTube, None) => format!("https://youtu.be/{id}"),
                 },
             ),
             (
                 String::from("embed-url"),
-                match status.provider {
-                    Provider::Invidious => {
+                match (status.provider, start) {
+                    (Provider::Invidious, Some(start)) => {
+                        format!("{}/embed/{}?t={start}", mainconfig.invidious_instance, id)
+                    }
+                    (Provider::Invidious, None) => {
                         format!("{}/embed/{}", mainconfig.invidious_instance, id)
                     }
-                    Provider::YouTube => format!("https://youtube.com/embed/{id}"),
+                    (Provider::YouTube, Some(start)) => {
+                        format!("https://youtube.com/embed/{id}?start={start}")
+                    }
+                    (Provider::YouTube, None) => format!("https://youtube.com/embed/{id}"),
                 },
             ),
+            // the start time in seconds, so that it can be passed to the video player
+            (String::from("start"), start.unwrap_or_default().to_string()),
             (
                 String::from("channel-url"),
                 match status.provider {
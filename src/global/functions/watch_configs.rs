@@ -0,0 +1,111 @@
+use crate::{
+    config::*,
+    global::{structs::*, traits::ConfigTrait},
+    load_configs,
+};
+use std::error::Error;
+use tui_additions::framework::{CursorState, Framework};
+
+/// reloads config files when any of them is changed, the current page is rebuilt if its layout
+/// in `pages.yml` is changed
+pub fn watch_configs(framework: &mut Framework) {
+    let changed = framework
+        .data
+        .global
+        .get_mut::<ConfigWatcher>()
+        .unwrap()
+        .changed();
+    if changed.is_empty() {
+        return;
+    }
+
+    // the current search is only replaced by the one in `search.yml` if that file is changed
+    let reload_search = changed
+        .iter()
+        .any(|path| path.file_stem().is_some_and(|stem| stem == Search::LABEL));
+    *framework.data.global.get_mut::<Message>().unwrap() =
+        match reload_configs(framework, reload_search) {
+            Ok(()) => Message::Success(String::from("Config files have been reloaded")),
+            // the current configs are kept until the files are fixed
+            Err(e) => Message::Error(e.to_string()),
+        };
+    framework
+        .data
+        .global
+        .get_mut::<Status>()
+        .unwrap()
+        .render_image = true;
+    framework
+        .data
+        .state
+        .get_mut::<Tasks>()
+        .unwrap()
+        .priority
+        .push(Task::RenderAll);
+}
+
+/// reloads all config files, and rebuilds the current page if its layout is changed, the active
+/// search is kept unless `reload_search` is set
+pub fn reload_configs(
+    framework: &mut Framework,
+    reload_search: bool,
+) -> Result<(), Box<dyn Error>> {
+    let old_layout = framework
+        .data
+        .state
+        .get::<Page>()
+        .unwrap()
+        .to_page_config(framework)
+        .layout;
+
+    let search = framework.data.state.get::<Search>().unwrap().clone();
+    load_configs(&mut framework.split_clean().0, None)?;
+    if !reload_search {
+        framework.data.state.insert::<Search>(search);
+    }
+
+    let page_config = framework
+        .data
+        .state
+        .get::<Page>()
+        .unwrap()
+        .to_page_config(framework);
+    if page_config.layout != old_layout {
+        rebuild_state(framework, &page_config);
+    }
+
+    Ok(())
+}
+
+/// replaces the items of the current page with the new layout and loads them again
+fn rebuild_state(framework: &mut Framework, page_config: &PageConfig) {
+    *framework.data.state.get_mut::<MinDimentions>().unwrap() =
+        MinDimentions::new(page_config.min_width(), page_config.min_height());
+    let state = page_config.to_state(framework);
+    framework.set_state(state);
+
+    // the cursor stays at the same position if it still exists, or moves to the closest one,
+    // selected items are deselected as they are replaced
+    framework.cursor = match framework.cursor {
+        CursorState::Hover(x, y) | CursorState::Selected(x, y)
+            if !framework.selectables.is_empty() =>
+        {
+            let y = y.min(framework.selectables.len() - 1);
+            CursorState::Hover(x.min(framework.selectables[y].len() - 1), y)
+        }
+        _ => CursorState::None,
+    };
+
+    let status = framework.data.global.get_mut::<Status>().unwrap();
+    status.popup_opened = false;
+    status.search_filter_opened = false;
+    status.search_suggestions_opened = false;
+
+    framework
+        .data
+        .state
+        .get_mut::<Tasks>()
+        .unwrap()
+        .priority
+        .push(Task::Reload);
+}
@@ -0,0 +1,527 @@
+//! editing yaml files as text, so that comments and formatting of config files are kept
+use serde_yaml::{Mapping, Value};
+
+/// indentation of a line in spaces
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// lines which do not affect the structure of the file
+fn is_blank(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty() || trimmed.starts_with('#')
+}
+
+/// the text after `key:` in a line, if the line is the key
+fn after_key<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let trimmed = line.trim_start();
+    [
+        format!("{key}:"),
+        format!("'{key}':"),
+        format!("\"{key}\":"),
+    ]
+    .iter()
+    .find_map(|prefix| trimmed.strip_prefix(prefix.as_str()))
+    .filter(|rest| rest.is_empty() || rest.starts_with(' '))
+}
+
+/// index of the line after the last line of the value of the key at `index`
+fn block_end(lines: &[String], index: usize) -> usize {
+    let key_indent = indent(&lines[index]);
+    let mut end = index + 1;
+
+    for (i, line) in lines.iter().enumerate().skip(index + 1) {
+        if is_blank(line) {
+            continue;
+        }
+        // sequences can be at the same indentation as their key
+        if indent(line) > key_indent
+            || (indent(line) == key_indent && line.trim_start().starts_with('-'))
+        {
+            end = i + 1;
+        } else {
+            break;
+        }
+    }
+
+    end
+}
+
+/// finds the line of a nested key, each part of the key is looked for in the value of the
+/// previous part
+pub fn find_key(lines: &[String], key: &[String]) -> Option<usize> {
+    let mut range = 0..lines.len();
+    let mut found = None;
+
+    for part in key {
+        // only keys directly in the current mapping are matched
+        let child_indent = lines[range.clone()]
+            .iter()
+            .find(|line| !is_blank(line))
+            .map(|line| indent(line))?;
+        let index = range.clone().find(|i| {
+            indent(&lines[*i]) == child_indent && after_key(&lines[*i], part).is_some()
+        })?;
+        range = index + 1..block_end(lines, index);
+        found = Some(index);
+    }
+
+    found
+}
+
+/// yaml of a mapping, indented by `indent` spaces
+fn to_lines(mapping: &Mapping, indent: usize) -> Vec<String> {
+    serde_yaml::to_string(mapping)
+        .unwrap_or_default()
+        .lines()
+        .map(|line| format!("{}{line}", " ".repeat(indent)))
+        .collect()
+}
+
+fn to_string(lines: Vec<String>) -> String {
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+fn parse_mapping(content: &str) -> Option<Mapping> {
+    match serde_yaml::from_str::<Value>(content).ok()? {
+        Value::Mapping(mapping) => Some(mapping),
+        // empty files
+        Value::Null => Some(Mapping::new()),
+        _ => None,
+    }
+}
+
+/// text of a key as it is written in the file, only strings and numbers are supported
+fn key_text(key: &Value) -> Option<String> {
+    match key {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// comment after a value on a single line, such as ` # comment` in `key: value # comment`
+fn trailing_comment(lines: &[String]) -> Option<String> {
+    let [line] = lines else {
+        return None;
+    };
+    let value = serde_yaml::from_str::<Value>(line.trim()).ok()?;
+
+    // `#` can also be part of the value, so the comment is only valid if the value stays the same
+    line.match_indices(" #")
+        .map(|(i, _)| i)
+        .find(|i| {
+            serde_yaml::from_str::<Value>(line[..*i].trim())
+                .ok()
+                .as_ref()
+                == Some(&value)
+        })
+        .map(|i| line[i..].to_string())
+}
+
+/// `file` with the keys in `config` which it does not have, including nested ones
+fn with_missing(file: &Value, config: &Value) -> Value {
+    match (file, config) {
+        (Value::Mapping(file), Value::Mapping(config)) => {
+            let mut merged = file.clone();
+            for (key, value) in config {
+                let value = match file.get(key) {
+                    Some(old) => with_missing(old, value),
+                    None => value.clone(),
+                };
+                merged.insert(key.clone(), value);
+            }
+            Value::Mapping(merged)
+        }
+        _ => file.clone(),
+    }
+}
+
+/// a change to the lines of a file
+enum Edit {
+    /// adds the keys before the line, indented by some spaces
+    Insert(usize, usize, Mapping),
+    /// replaces the lines in the range with the key, indented by some spaces
+    Replace(usize, usize, usize, Mapping),
+    Remove(usize, usize),
+}
+
+impl Edit {
+    /// edits are applied from the bottom, so that line numbers of the edits above are not changed,
+    /// at the same line, replacing comes before inserting, and shallower keys are inserted first
+    /// so that they end up below the deeper ones
+    fn order(&self) -> (usize, u8, std::cmp::Reverse<usize>) {
+        match self {
+            Self::Insert(index, indent, _) => (*index, 0, std::cmp::Reverse(*indent)),
+            Self::Replace(index, _, _, _) | Self::Remove(index, _) => {
+                (*index, 1, std::cmp::Reverse(0))
+            }
+        }
+    }
+}
+
+/// compares `file` with `config`, keys missing in the file are always added, `update` also
+/// replaces changed values and removes keys not in `config`
+fn diff(
+    lines: &[String],
+    file: &Mapping,
+    config: &Mapping,
+    path: &mut Vec<String>,
+    update: bool,
+    edits: &mut Vec<Edit>,
+) -> Option<()> {
+    let missing = config
+        .iter()
+        .filter(|(key, _)| !file.contains_key(*key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Mapping>();
+
+    if !missing.is_empty() {
+        if path.is_empty() {
+            edits.push(Edit::Insert(lines.len(), 0, missing));
+        } else {
+            let index = find_key(lines, path)?;
+            // values written inline (`key: {}`) cannot be added to
+            if !after_key(&lines[index], path.last()?).is_some_and(is_blank) {
+                return None;
+            }
+            let end = block_end(lines, index);
+            let child_indent = lines[index + 1..end]
+                .iter()
+                .find(|line| !is_blank(line))
+                .map(|line| indent(line))
+                .unwrap_or(indent(&lines[index]) + 2);
+            edits.push(Edit::Insert(end, child_indent, missing));
+        }
+    }
+
+    for (key, old) in file {
+        let new = config.get(key);
+        if new == Some(old) {
+            continue;
+        }
+
+        path.push(key_text(key)?);
+        match (old, new) {
+            (Value::Mapping(old_mapping), Some(Value::Mapping(new_mapping))) => {
+                let index = find_key(lines, path)?;
+                if after_key(&lines[index], path.last()?).is_some_and(is_blank) {
+                    diff(lines, old_mapping, new_mapping, path, update, edits)?
+                } else {
+                    // values written inline (`key: {a: 1}`) cannot be added to, so they are
+                    // replaced as a whole
+                    let value = if update {
+                        new.cloned()?
+                    } else {
+                        with_missing(old, new?)
+                    };
+                    if &value != old {
+                        edits.push(Edit::Replace(
+                            index,
+                            block_end(lines, index),
+                            indent(&lines[index]),
+                            Mapping::from_iter([(key.clone(), value)]),
+                        ));
+                    }
+                }
+            }
+            _ if !update => {}
+            (_, Some(new)) => {
+                let index = find_key(lines, path)?;
+                edits.push(Edit::Replace(
+                    index,
+                    block_end(lines, index),
+                    indent(&lines[index]),
+                    Mapping::from_iter([(key.clone(), new.clone())]),
+                ));
+            }
+            (_, None) => {
+                let index = find_key(lines, path)?;
+                edits.push(Edit::Remove(index, block_end(lines, index)));
+            }
+        }
+        path.pop();
+    }
+
+    Some(())
+}
+
+fn apply_diff(content: &str, config: &Value, update: bool) -> Option<String> {
+    let file = parse_mapping(content)?;
+    let config = config.as_mapping()?;
+    let mut lines = content.lines().map(str::to_string).collect::<Vec<_>>();
+    // files written as a single flow mapping have no lines to add keys to
+    if lines
+        .iter()
+        .find(|line| !is_blank(line))
+        .is_some_and(|line| line.trim_start().starts_with('{'))
+    {
+        return None;
+    }
+
+    let mut edits = Vec::new();
+    diff(&lines, &file, config, &mut Vec::new(), update, &mut edits)?;
+    if edits.is_empty() {
+        return Some(content.to_string());
+    }
+
+    edits.sort_by_key(Edit::order);
+    for edit in edits.into_iter().rev() {
+        match edit {
+            Edit::Insert(index, indent, mapping) => {
+                lines.splice(index..index, to_lines(&mapping, indent));
+            }
+            Edit::Replace(index, end, indent, mapping) => {
+                let mut new_lines = to_lines(&mapping, indent);
+                if let ([new_line], Some(comment)) = (
+                    new_lines.as_mut_slice(),
+                    trailing_comment(&lines[index..end]),
+                ) {
+                    new_line.push_str(&comment);
+                }
+                lines.splice(index..end, new_lines);
+            }
+            Edit::Remove(index, end) => {
+                lines.drain(index..end);
+            }
+        }
+    }
+
+    Some(to_string(lines))
+}
+
+/// adds keys (including nested ones) in `config` which are missing in the file, without
+/// changing anything else, returns `None` if the file cannot be edited
+pub fn append_missing_keys(content: &str, config: &Value) -> Option<String> {
+    apply_diff(content, config, false)
+}
+
+/// makes the file the same as `config`, only changing lines of keys which values are different,
+/// returns `None` if the file cannot be edited
+pub fn update_keys(content: &str, config: &Value) -> Option<String> {
+    apply_diff(content, config, true)
+}
+
+/// renames the last part of a nested key, keeping its value and comments, returns `None` if the
+/// key is not found
+pub fn rename_key(content: &str, key: &[&str], to: &str) -> Option<String> {
+    let key = key.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let mut lines = content.lines().map(str::to_string).collect::<Vec<_>>();
+    let index = find_key(&lines, &key)?;
+
+    let rest = after_key(&lines[index], key.last()?)?.to_string();
+    lines[index] = format!("{}{to}:{rest}", " ".repeat(indent(&lines[index])));
+
+    Some(to_string(lines))
+}
+
+/// removes a nested key and its value, returns `None` if the key is not found
+pub fn remove_key(content: &str, key: &[&str]) -> Option<String> {
+    let key = key.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let mut lines = content.lines().map(str::to_string).collect::<Vec<_>>();
+    let index = find_key(&lines, &key)?;
+
+    let end = block_end(&lines, index);
+    lines.drain(index..end);
+
+    Some(to_string(lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(yaml: &str) -> Value {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    /// the file is edited to `config`, and still parses to the same value
+    fn assert_update(content: &str, config: &str, expected: &str) {
+        let output = update_keys(content, &value(config)).unwrap();
+        assert_eq!(output, expected);
+        assert_eq!(value(&output), value(config));
+    }
+
+    #[test]
+    fn appends_nested_keys() {
+        let content = "# comment\na:\n  b: 1 # keep\nc: 2\n";
+        let output = append_missing_keys(content, &value("a: {b: 5, d: 3}\nc: 2\ne: 4")).unwrap();
+        assert_eq!(output, "# comment\na:\n  b: 1 # keep\n  d: 3\nc: 2\ne: 4\n");
+    }
+
+    #[test]
+    fn flow_mappings() {
+        // missing keys are added to the existing values
+        let content = "a: {b: 1}\nc: 2\n";
+        let output = append_missing_keys(content, &value("a: {b: 5, d: 3}\nc: 2")).unwrap();
+        assert_eq!(output, "a:\n  b: 1\n  d: 3\nc: 2\n");
+
+        assert_update("a: {b: 1}\nc: 2\n", "a: {b: 5}\nc: 2", "a:\n  b: 5\nc: 2\n");
+        assert_update("a: {b: 1}\nc: 2\n", "a: {b: 1}\nc: 3", "a: {b: 1}\nc: 3\n");
+        assert!(append_missing_keys("{a: 1}\n", &value("a: 1\nb: 2")).is_none());
+    }
+
+    #[test]
+    fn multi_line_scalars() {
+        let content = "a: |\n  line 1\n  line 2\nb: >-\n  folded\n  text\nc: 1\n";
+        assert_update(
+            content,
+            "a: \"new\\n\"\nb: folded text\nc: 2",
+            "a: |\n  new\nb: >-\n  folded\n  text\nc: 2\n",
+        );
+
+        let output = append_missing_keys(content, &value("d: 1")).unwrap();
+        assert_eq!(output, format!("{content}d: 1\n"));
+    }
+
+    #[test]
+    fn indented_sequences() {
+        // sequences indented under their key, and at the same indentation as it
+        let content = "a:\n  - 1\n  - 2\nb:\n- 3\n- 4\nc: 5\n";
+        assert_update(
+            content,
+            "a: [1, 2]\nb: [3]\nc: 5",
+            "a:\n  - 1\n  - 2\nb:\n- 3\nc: 5\n",
+        );
+        assert_update(
+            content,
+            "a: [1, 2]\nb: [3, 4]",
+            "a:\n  - 1\n  - 2\nb:\n- 3\n- 4\n",
+        );
+
+        let nested = "a:\n  b:\n  - 1\n  c: 2\n";
+        let output = append_missing_keys(nested, &value("a: {b: [1], c: 2, d: 3}")).unwrap();
+        assert_eq!(output, "a:\n  b:\n  - 1\n  c: 2\n  d: 3\n");
+    }
+
+    #[test]
+    fn quoted_keys() {
+        let content = "'a': 1\n\"b\":\n  'c': 2\n";
+        assert_update(content, "a: 3\nb: {c: 2}", "a: 3\n\"b\":\n  'c': 2\n");
+        assert_update(content, "a: 1\nb: {c: 4}", "'a': 1\n\"b\":\n  c: 4\n");
+        assert_eq!(
+            find_key(
+                &content.lines().map(String::from).collect::<Vec<_>>(),
+                &[String::from("b"), String::from("c")]
+            ),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn insert_and_replace_at_same_line() {
+        // `d` is inserted at the end of `a`, which is the line `c` is replaced at
+        let content = "a:\n  b: 1\nc: 2\n";
+        assert_update(
+            content,
+            "a: {b: 1, d: 3}\nc: 4",
+            "a:\n  b: 1\n  d: 3\nc: 4\n",
+        );
+
+        // inserted keys end up below deeper ones
+        let content = "a:\n  b:\n    c: 1\n";
+        assert_update(
+            content,
+            "a: {b: {c: 1, d: 2}, e: 3}\nf: 4",
+            "a:\n  b:\n    c: 1\n    d: 2\n  e: 3\nf: 4\n",
+        );
+    }
+
+    #[test]
+    fn trailing_comments() {
+        let content = "a: 1 # one\nb: 'x # not a comment'\nc: '#' # hash\n";
+        assert_update(
+            content,
+            "a: 2\nb: 'x # not a comment'\nc: '!'",
+            "a: 2 # one\nb: 'x # not a comment'\nc: '!' # hash\n",
+        );
+        assert_update(
+            content,
+            "a: 1\nb: y\nc: '#'",
+            "a: 1 # one\nb: y\nc: '#' # hash\n",
+        );
+    }
+
+    #[test]
+    fn removes_keys() {
+        let content = "a: 1\n# about b\nb:\n  c: 1\n  d: 2\ne: 3\n";
+        assert_update(
+            content,
+            "a: 1\nb: {c: 1}\ne: 3",
+            "a: 1\n# about b\nb:\n  c: 1\ne: 3\n",
+        );
+        assert_update(content, "a: 1\ne: 3", "a: 1\n# about b\ne: 3\n");
+    }
+
+    #[test]
+    fn renames_keys() {
+        let content = "a:\n  b: 1 # keep\n  c:\n  - 2\nb: 3\n";
+        assert_eq!(
+            rename_key(content, &["a", "b"], "d").unwrap(),
+            "a:\n  d: 1 # keep\n  c:\n  - 2\nb: 3\n"
+        );
+        assert_eq!(
+            rename_key(content, &["a", "c"], "e").unwrap(),
+            "a:\n  b: 1 # keep\n  e:\n  - 2\nb: 3\n"
+        );
+        assert_eq!(rename_key(content, &["a", "x"], "e"), None);
+    }
+
+    #[test]
+    fn removes_nested_keys() {
+        let content = "a:\n  b:\n    c: 1\n  # about d\n  d: 2\ne: 3\n";
+        assert_eq!(
+            remove_key(content, &["a", "b"]).unwrap(),
+            "a:\n  # about d\n  d: 2\ne: 3\n"
+        );
+        assert_eq!(remove_key(content, &["a"]).unwrap(), "e: 3\n");
+        assert_eq!(remove_key(content, &["e", "f"]), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let content = r#"# config file
+mouse_support: true # use the mouse
+'invidious_instance': https://example.com
+env:
+  browser: firefox
+  video-player: mpv # player
+layout:
+- type: itemlist
+- type: row
+  items: [a, b]
+description: |
+  some
+  text
+flow: {a: 1, b: [1, 2]}
+removed: 1
+"#;
+        let config = value(
+            r#"
+mouse_support: false
+invidious_instance: https://example.org
+env:
+  browser: librewolf
+  video-player: mpv
+  terminal: konsole
+layout:
+- type: itemlist
+- type: row
+  items: [a, c]
+description: "other\n"
+flow: {a: 2, b: [1, 2], c: {}}
+new:
+  nested: [1, {a: b}]
+"#,
+        );
+
+        let output = update_keys(content, &config).unwrap();
+        assert_eq!(value(&output), config);
+        assert!(output.starts_with("# config file\nmouse_support: false # use the mouse\n"));
+        assert!(output.contains("  video-player: mpv # player\n"));
+        // nothing changes when the file is already the same
+        assert_eq!(update_keys(&output, &config).unwrap(), output);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::global::{
+    functions::{config_dir, find_key, profile_label},
+    traits::EXTENSION,
+};
+use std::{error::Error, fmt::Display, fs};
+
+/// a problem found when reading a config file
+#[derive(Clone, Debug)]
+pub struct ConfigIssue {
+    /// name of the file, such as `main.yml`
+    pub file: String,
+    /// line and column, starting from 1
+    pub location: Option<(usize, usize)>,
+    /// path to the offending key, such as `["colors", "text"]`
+    pub key: Vec<String>,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    /// an invalid value of a key, the file and location are filled in by `in_config()`
+    pub fn new(key: &[&str], message: String) -> Self {
+        Self {
+            file: String::new(),
+            location: None,
+            key: key.iter().map(|s| s.to_string()).collect(),
+            message,
+        }
+    }
+
+    /// from an error when parsing a config file
+    pub fn from_yaml(label: &str, e: &serde_yaml::Error) -> Self {
+        let location = e
+            .location()
+            .map(|location| (location.line(), location.column()));
+        let mut message = e.to_string();
+        if let Some((line, column)) = location {
+            message = message.replace(&format!(" at line {line} column {column}"), "");
+        }
+
+        // serde_yaml puts the path to the key in front of the message
+        let key = match message.split_once(": ") {
+            Some((key, rest)) if !key.contains(' ') => {
+                let key = key.split('.').map(str::to_string).collect();
+                message = rest.to_string();
+                key
+            }
+            _ => Vec::new(),
+        };
+
+        Self {
+            file: format!("{label}.{EXTENSION}"),
+            location,
+            key,
+            message,
+        }
+    }
+
+    /// sets the file, and finds where the key is in it if the location is unknown, keys set by
+    /// the current profile are found in the profile instead
+    pub fn in_config(mut self, label: &str) -> Self {
+        self.file = format!("{label}.{EXTENSION}");
+        if self.location.is_some() {
+            return self;
+        }
+
+        for label in profile_label(label).into_iter().chain([label.to_string()]) {
+            let file = format!("{label}.{EXTENSION}");
+            let Ok(content) = fs::read_to_string(config_dir().join(&file)) else {
+                continue;
+            };
+            let lines = content.lines().map(str::to_string).collect::<Vec<_>>();
+            if let Some(index) = find_key(&lines, &self.key) {
+                self.file = file;
+                self.location = Some((
+                    index + 1,
+                    lines[index].len() - lines[index].trim_start().len() + 1,
+                ));
+                break;
+            }
+        }
+        self
+    }
+
+    /// converts any error from loading a config file
+    pub fn from_error(label: &str, e: Box<dyn Error>) -> Self {
+        match e.downcast::<Self>() {
+            Ok(issue) => *issue,
+            Err(e) => Self {
+                file: format!("{label}.{EXTENSION}"),
+                location: None,
+                key: Vec::new(),
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.file)?;
+        if let Some((line, column)) = self.location {
+            write!(f, ":{line}:{column}")?;
+        }
+        if !self.key.is_empty() {
+            write!(f, ": `{}`", self.key.join("."))?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl Error for ConfigIssue {}
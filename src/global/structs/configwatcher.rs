@@ -0,0 +1,65 @@
+use crate::global::{
+    functions::{config_dir, profile, profiles_dir},
+    traits::EXTENSION,
+};
+use std::{collections::HashMap, fs, path::PathBuf, time::SystemTime};
+use typemap::Key;
+
+/// last modified times of config files, stored in `data.global` to reload them when changed
+#[derive(Clone, Default)]
+pub struct ConfigWatcher(pub HashMap<PathBuf, SystemTime>);
+
+impl Key for ConfigWatcher {
+    type Value = Self;
+}
+
+impl ConfigWatcher {
+    /// the current state of all config files, including the ones in the current profile
+    pub fn new() -> Self {
+        let dirs = [
+            Some(config_dir()),
+            profile().map(|name| profiles_dir().join(name)),
+        ];
+
+        Self(
+            dirs.into_iter()
+                .flatten()
+                .filter_map(|dir| fs::read_dir(dir).ok())
+                .flat_map(|entries| entries.flatten())
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == EXTENSION))
+                .filter_map(|path| {
+                    let modified = fs::metadata(&path).ok()?.modified().ok()?;
+                    Some((path, modified))
+                })
+                .collect(),
+        )
+    }
+
+    /// config files that are created, modified or removed since the last check
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let current = Self::new();
+        let mut changed = current
+            .0
+            .iter()
+            .filter(|(path, modified)| self.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .chain(
+                self.0
+                    .keys()
+                    .filter(|path| !current.0.contains_key(*path))
+                    .cloned(),
+            )
+            .collect::<Vec<_>>();
+        changed.sort();
+
+        *self = current;
+        changed
+    }
+
+    /// takes a new snapshot without reporting changes, called after the app saves a config file
+    /// so that its own changes are not reloaded
+    pub fn refresh(&mut self) {
+        *self = Self::new();
+    }
+}
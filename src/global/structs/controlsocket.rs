@@ -0,0 +1,25 @@
+use std::sync::{
+    mpsc::{Receiver, Sender},
+    Arc, Mutex,
+};
+use typemap::Key;
+
+/// a command received from the control socket, and where to send the reply to
+pub type RemoteRequest = (String, Sender<String>);
+
+/// state of the control socket, stored in `data.global`
+#[derive(Clone, Default)]
+pub struct ControlSocket {
+    /// receives commands from connected programs, `None` if the socket is not listening
+    pub receiver: Option<Arc<Mutex<Receiver<RemoteRequest>>>>,
+    /// programs waiting for the status after their commands are ran
+    pub waiting: Vec<Sender<String>>,
+}
+
+impl Key for ControlSocket {
+    type Value = Self;
+}
+
+impl ControlSocket {
+    pub const PATH: &'static str = "control.sock";
+}
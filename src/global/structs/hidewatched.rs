@@ -0,0 +1,56 @@
+use super::{ChannelDisplayPage, ChannelDisplayPageType, Item, MainMenuPage, Page, WatchHistory};
+use std::collections::HashSet;
+use typemap::Key;
+
+/// pages which watched videos are hidden in, toggled with the `togglewatched` command
+#[derive(Clone, Default)]
+pub struct HideWatched(pub HashSet<&'static str>);
+
+impl Key for HideWatched {
+    type Value = Self;
+}
+
+impl HideWatched {
+    /// returns the label of the page, or none if watched videos cannot be hidden in that page
+    pub fn label(page: &Page) -> Option<&'static str> {
+        match page {
+            Page::MainMenu(MainMenuPage::Trending(_)) => Some("trending"),
+            Page::MainMenu(MainMenuPage::Popular) => Some("popular"),
+            Page::Search(_) => Some("search"),
+            Page::MainMenu(MainMenuPage::SavedSearch(_)) => Some("saved search"),
+            Page::ChannelDisplay(ChannelDisplayPage {
+                r#type:
+                    ChannelDisplayPageType::Videos
+                    | ChannelDisplayPageType::Shorts
+                    | ChannelDisplayPageType::Streams
+                    | ChannelDisplayPageType::Search,
+                ..
+            }) => Some("channel videos"),
+            _ => None,
+        }
+    }
+
+    /// returns true if watched videos should be hidden in the page
+    pub fn hides(&self, page: &Page) -> bool {
+        Self::label(page).is_some_and(|label| self.0.contains(label))
+    }
+
+    /// removes watched items if they should be hidden in the page
+    pub fn apply(&self, page: &Page, watch_history: &WatchHistory, items: &mut Vec<Item>) {
+        if self.hides(page) {
+            items.retain(|item| !item.id().is_some_and(|id| watch_history.contains(id)))
+        }
+    }
+
+    /// toggles hiding watched videos in the page, returns whether they are now hidden, or none if
+    /// the page is not supported
+    pub fn toggle(&mut self, page: &Page) -> Option<bool> {
+        let label = Self::label(page)?;
+        if self.0.remove(label) {
+            Some(false)
+        } else {
+            self.0.insert(label);
+            Some(true)
+        }
+    }
+}
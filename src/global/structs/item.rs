@@ -1,8 +1,9 @@
-use crate::global::functions::*;
+use crate::{config::Search, global::functions::*};
 use invidious::structs::{
     channel::Channel, hidden::*, universal::Playlist as FullPlaylist, video::Video,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::fmt::Display;
 
 use super::Errors;
@@ -19,7 +20,11 @@ pub enum Item {
     FullVideo(FullVideoItem),
     FullPlaylist(FullPlaylistItem),
     FullChannel(FullChannelItem),
-    Page(bool), // true: next false: prev
+    CommunityPost(CommunityPostItem),
+    SearchQuery(Search), // a past search in the search history page
+    SavedSearch(String), // name of a saved search in the search history page
+    Page(bool),          // true: next false: prev
+    LoadMore,            // appends the next batch of items to the list
     Unknown(SearchItemTransition),
 }
 
@@ -106,6 +111,79 @@ pub struct FullChannelItem {
     pub description: String,
 }
 
+/// stores information of a post in the community tab of a channel
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CommunityPostItem {
+    pub id: String,
+    pub channel: String,
+    pub channel_id: String,
+    pub content: String,
+    pub likes: String,
+    pub published: String,
+    pub attachment: Option<PostAttachment>,
+}
+
+/// things that can be attached to a community post
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum PostAttachment {
+    /// url of the image, only the first image is kept if there are multiple
+    Image(String),
+    Poll {
+        choices: Vec<String>,
+        total_votes: u64,
+    },
+    Video {
+        title: String,
+        id: String,
+    },
+    Playlist {
+        title: String,
+        id: String,
+    },
+}
+
+impl PostAttachment {
+    /// parse the `attachment` of a post from the invidious api, unsupported attachments are
+    /// ignored
+    pub fn from_json(value: Value) -> Option<Self> {
+        let str_field = |key: &str| value.get(key)?.as_str().map(String::from);
+        // the largest image is used
+        let largest_image = |images: &Value| {
+            images
+                .as_array()?
+                .iter()
+                .max_by_key(|image| image.get("width").and_then(Value::as_u64))?
+                .get("url")?
+                .as_str()
+                .map(String::from)
+        };
+
+        Some(match value.get("type")?.as_str()? {
+            "image" => Self::Image(largest_image(value.get("imageThumbnails")?)?),
+            "multiImage" => Self::Image(largest_image(value.get("images")?.get(0)?)?),
+            "poll" => Self::Poll {
+                choices: value
+                    .get("choices")?
+                    .as_array()?
+                    .iter()
+                    .filter_map(|choice| choice.get("text")?.as_str().map(String::from))
+                    .collect(),
+                total_votes: value.get("totalVotes").and_then(Value::as_u64).unwrap_or(0),
+            },
+            "video" => Self::Video {
+                title: str_field("title")?,
+                id: str_field("videoId")?,
+            },
+            "playlist" => Self::Playlist {
+                title: str_field("title")?,
+                id: str_field("playlistId")?,
+            },
+            _ => return None,
+        })
+    }
+}
+
 impl Display for Item {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
@@ -115,6 +193,14 @@ impl Display for Item {
             Self::FullVideo(video) => &video.title,
             Self::FullPlaylist(playlist) => &playlist.title,
             Self::FullChannel(channel) => &channel.name,
+            // the first line of the post is used as its title
+            Self::CommunityPost(post) => post
+                .content
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or("Community post"),
+            Self::SearchQuery(search) => &search.query,
+            Self::SavedSearch(name) => name,
             Self::Page(b) => {
                 if *b {
                     "Next page"
@@ -122,6 +208,7 @@ impl Display for Item {
                     "Previous page"
                 }
             }
+            Self::LoadMore => "Load more",
             Self::Unknown(_) => "Unknown item",
         })
     }
@@ -136,8 +223,13 @@ impl Item {
             | Self::MiniPlaylist(MiniPlaylistItem { id, .. })
             | Self::FullVideo(FullVideoItem { id, .. })
             | Self::FullChannel(FullChannelItem { id, .. })
-            | Self::FullPlaylist(FullPlaylistItem { id, .. }) => Some(id),
-            Self::Unknown(_) | Self::Page(_) => None,
+            | Self::FullPlaylist(FullPlaylistItem { id, .. })
+            | Self::CommunityPost(CommunityPostItem { id, .. }) => Some(id),
+            Self::SearchQuery(_)
+            | Self::SavedSearch(_)
+            | Self::Unknown(_)
+            | Self::Page(_)
+            | Self::LoadMore => None,
         }
     }
 
@@ -194,6 +286,14 @@ impl Item {
             _ => Err(Errors::StrError("not a full channel")),
         }
     }
+
+    /// stript self into a CommunityPostItem
+    pub fn communitypost(&self) -> Result<&CommunityPostItem, Errors> {
+        match self {
+            Self::CommunityPost(communitypost) => Ok(communitypost),
+            _ => Err(Errors::StrError("not a community post")),
+        }
+    }
 }
 
 impl Item {
@@ -207,7 +307,17 @@ impl Item {
             Self::FullVideo(video) => &video.id,
             Self::FullPlaylist(playlist) => &playlist.id,
             Self::FullChannel(channel) => &channel.id,
-            Self::Unknown(_) | Self::Page(_) => "invalid",
+            Self::CommunityPost(CommunityPostItem {
+                id,
+                attachment: Some(PostAttachment::Image(_)),
+                ..
+            }) => id,
+            Self::CommunityPost(_)
+            | Self::SearchQuery(_)
+            | Self::SavedSearch(_)
+            | Self::Unknown(_)
+            | Self::Page(_)
+            | Self::LoadMore => "invalid",
         }
     }
 
@@ -425,4 +535,21 @@ impl Item {
             thumbnail_url: original.thumbnail,
         })
     }
+
+    /// parse `CommunityPost` into `Self`
+    pub fn from_community_post(original: CommunityPost) -> Self {
+        Self::CommunityPost(CommunityPostItem {
+            id: original.id,
+            channel: original.author,
+            channel_id: original.author_id,
+            content: original.content,
+            likes: viewcount_text(original.likes),
+            published: format!(
+                "{} [{}]",
+                original.published_text,
+                date_text(original.published)
+            ),
+            attachment: original.attachment.and_then(PostAttachment::from_json),
+        })
+    }
 }
@@ -0,0 +1,16 @@
+use typemap::Key;
+
+/// incremented whenever the watch history or unread videos change, item lists only rebuild their
+/// labels when it is different from the one they were built with
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct LabelsRevision(pub usize);
+
+impl Key for LabelsRevision {
+    type Value = Self;
+}
+
+impl LabelsRevision {
+    pub fn bump(&mut self) {
+        self.0 += 1;
+    }
+}
@@ -0,0 +1,90 @@
+use crate::global::{functions::FeedEntry, traits::DataFile};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use typemap::Key;
+
+/// per channel timestamp of the latest video that has been seen in the subscriptions feed, videos
+/// published after it are unread
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct LastSeen {
+    pub channels: HashMap<String, u64>,
+    /// unread videos from the last loaded feed, maps video id to (channel id, published)
+    #[serde(skip)]
+    pub unread: HashMap<String, (String, u64)>,
+}
+
+impl Key for LastSeen {
+    type Value = Self;
+}
+
+impl DataFile for LastSeen {
+    const PATH: &'static str = "last_seen.json";
+}
+
+impl LastSeen {
+    /// replace unread videos with the ones in a newly fetched feed
+    // channels that are not tracked yet have all their current videos treated as seen, so newly
+    // subscribed channels does not flood the feed with unread videos
+    pub fn update(&mut self, entries: &[FeedEntry]) {
+        for entry in entries.iter() {
+            if let Some(channel_id) = entry.channel_id() {
+                if !self.channels.contains_key(channel_id) {
+                    let latest = entries
+                        .iter()
+                        .filter(|other| other.channel_id() == Some(channel_id))
+                        .map(|other| other.published)
+                        .max()
+                        .unwrap_or_default();
+                    self.channels.insert(channel_id.to_string(), latest);
+                }
+            }
+        }
+
+        self.unread = entries
+            .iter()
+            .filter_map(|entry| {
+                let channel_id = entry.channel_id()?;
+                if entry.published <= *self.channels.get(channel_id)? {
+                    return None;
+                }
+                Some((
+                    entry.item.id()?.to_string(),
+                    (channel_id.to_string(), entry.published),
+                ))
+            })
+            .collect();
+    }
+
+    pub fn is_unread(&self, id: &str) -> bool {
+        self.unread.contains_key(id)
+    }
+
+    /// number of unread videos
+    pub fn count(&self) -> usize {
+        self.unread.len()
+    }
+
+    /// marks a video and all older videos from the same channel as read, returns false if the
+    /// video is not unread
+    pub fn mark_read(&mut self, id: &str) -> bool {
+        let (channel_id, published) = match self.unread.get(id) {
+            Some(unread) => unread.clone(),
+            None => return false,
+        };
+
+        let last_seen = self.channels.entry(channel_id.clone()).or_default();
+        *last_seen = (*last_seen).max(published);
+        self.unread
+            .retain(|_, (channel, time)| *channel != channel_id || *time > published);
+
+        true
+    }
+
+    /// marks all videos as read
+    pub fn mark_all_read(&mut self) {
+        for (channel_id, published) in self.unread.drain().map(|(_, unread)| unread) {
+            let last_seen = self.channels.entry(channel_id).or_default();
+            *last_seen = (*last_seen).max(published);
+        }
+    }
+}
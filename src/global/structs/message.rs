@@ -1,8 +1,10 @@
+use serde::Serialize;
 use typemap::Key;
 
 /// Messages in the message bar
 // is stored in `data.global`
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "text")]
 pub enum Message {
     Message(String),
     Error(String),
@@ -1,24 +1,44 @@
 //! enums and structs
+mod configissue;
+mod configwatcher;
+mod controlsocket;
 mod errors;
+mod hidewatched;
 mod invidiousclient;
 mod item;
 mod keyaction;
+mod labelsrevision;
+mod lastseen;
 mod library;
 mod message;
 mod page;
+mod refresher;
+mod savedsearches;
+mod searchhistory;
 mod state_env;
 mod status;
+mod subscriptions;
 mod tasks;
 mod watchhistory;
 
+pub use configissue::*;
+pub use configwatcher::*;
+pub use controlsocket::*;
 pub use errors::*;
+pub use hidewatched::*;
 pub use invidiousclient::*;
 pub use item::*;
 pub use keyaction::*;
+pub use labelsrevision::*;
+pub use lastseen::*;
 pub use library::*;
 pub use message::*;
 pub use page::*;
+pub use refresher::*;
+pub use savedsearches::*;
+pub use searchhistory::*;
 pub use state_env::*;
 pub use status::*;
+pub use subscriptions::*;
 pub use tasks::*;
 pub use watchhistory::*;
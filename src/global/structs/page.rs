@@ -1,12 +1,13 @@
 use std::fmt::Debug;
 
 use crate::config::*;
+use ::serde::Serialize;
 use tui_additions::framework::Framework;
 use typemap::Key;
 
 // Page can be converted into PageConfig, which can then be converted into State
 /// Covers all possible pages and variants
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub enum Page {
     MainMenu(MainMenuPage),
     Search(Search),
@@ -49,39 +50,135 @@ impl Key for Page {
 }
 
 /// page variants for the main menu
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub enum MainMenuPage {
-    Trending,
+    Trending(TrendingTab),
     Popular,
     History,
     Subscriptions,
     Library,
+    SearchHistory,
+    /// feed of a saved search, by its name
+    SavedSearch(String),
 }
 
 impl Default for MainMenuPage {
     fn default() -> Self {
-        Self::Trending
+        Self::Trending(TrendingTab::default())
+    }
+}
+
+/// categories of the trending page
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum TrendingTab {
+    #[default]
+    Now,
+    Music,
+    Gaming,
+    News,
+    Movies,
+}
+
+impl TrendingTab {
+    /// the value of the `type` query parameter, `None` for the default tab
+    pub fn as_type(&self) -> Option<&'static str> {
+        match self {
+            Self::Now => None,
+            Self::Music => Some("Music"),
+            Self::Gaming => Some("Gaming"),
+            Self::News => Some("News"),
+            Self::Movies => Some("Movies"),
+        }
+    }
+
+    /// query parameters for loading the tab from a region
+    pub fn params(&self, region: &str) -> String {
+        match self.as_type() {
+            Some(r#type) => format!("region={region}&type={type}"),
+            None => format!("region={region}"),
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s {
+            "now" => Some(Self::Now),
+            "music" => Some(Self::Music),
+            "gaming" => Some(Self::Gaming),
+            "news" => Some(Self::News),
+            "movies" => Some(Self::Movies),
+            _ => None,
+        }
     }
 }
 
 /// variants of the coannel display page
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub struct ChannelDisplayPage {
     pub id: String,
     pub r#type: ChannelDisplayPageType,
+    /// sort order of the videos page
+    pub sort: ChannelSort,
+    /// query of the search page
+    pub query: String,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ChannelDisplayPageType {
     Main,
     Videos,
+    Shorts,
+    /// past and upcoming live streams
+    Streams,
     Playlists,
+    Community,
+    /// videos matching `query`, within the channel
+    Search,
+}
+
+/// sort order of videos in a channel
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum ChannelSort {
+    #[default]
+    Newest,
+    Popular,
+    Oldest,
+}
+
+impl ChannelSort {
+    /// the value of the `sort_by` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Newest => "newest",
+            Self::Popular => "popular",
+            Self::Oldest => "oldest",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s {
+            "newest" => Some(Self::Newest),
+            "popular" => Some(Self::Popular),
+            "oldest" => Some(Self::Oldest),
+            _ => None,
+        }
+    }
+
+    /// the next sort order when cycling through them
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Newest => Self::Popular,
+            Self::Popular => Self::Oldest,
+            Self::Oldest => Self::Newest,
+        }
+    }
 }
 
 /// Different items to be displayed on a single item page
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub enum SingleItemPage {
-    Video(String),
+    /// video id and the time to start playing from in seconds, which is only set when loaded
+    /// from an url with a timestamp
+    Video(String, Option<u64>),
     Playlist(String),
 }
 
@@ -89,6 +186,7 @@ impl Page {
     pub fn to_page_config(&self, framework: &Framework) -> PageConfig {
         let pages_config = framework.data.global.get::<PagesConfig>().unwrap();
         match self {
+            Self::MainMenu(MainMenuPage::Trending(_)) => pages_config.trending.clone(),
             Self::MainMenu(_) => pages_config.main_menu.clone(),
             Self::Search(_) => pages_config.search.clone(),
             Self::SingleItem(_) => pages_config.singleitem.clone(),
@@ -100,6 +198,7 @@ impl Page {
     pub fn load_msg(&self, framework: &Framework) -> String {
         let pages_config = framework.data.global.get::<PagesConfig>().unwrap();
         match self {
+            Self::MainMenu(MainMenuPage::Trending(_)) => pages_config.trending.message.clone(),
             Self::MainMenu(_) => pages_config.main_menu.message.clone(),
             Self::Search(_) => pages_config.search.message.clone(),
             Self::SingleItem(_) => pages_config.singleitem.message.clone(),
@@ -0,0 +1,41 @@
+use super::Item;
+use crate::global::functions::FeedEntry;
+use std::{
+    sync::{mpsc::Receiver, Arc, Mutex},
+    time::Instant,
+};
+use typemap::Key;
+
+/// result of a background refresh
+pub type RefreshResult = Result<Vec<FeedEntry>, String>;
+/// results of each saved search from a background refresh, saved searches that failed to load are
+/// left out
+pub type SavedSearchesRefresh = Vec<(String, Vec<Item>)>;
+
+/// state of the background subscriptions refresher, stored in `data.global`
+#[derive(Clone)]
+pub struct Refresher {
+    /// when the last refresh is started
+    pub last_refresh: Instant,
+    /// receives the result of a refresh that is still running
+    pub pending: Option<Arc<Mutex<Receiver<RefreshResult>>>>,
+    /// when the last refresh of saved searches is started
+    pub last_search_refresh: Instant,
+    /// receives the results of a saved searches refresh that is still running
+    pub pending_searches: Option<Arc<Mutex<Receiver<SavedSearchesRefresh>>>>,
+}
+
+impl Key for Refresher {
+    type Value = Self;
+}
+
+impl Default for Refresher {
+    fn default() -> Self {
+        Self {
+            last_refresh: Instant::now(),
+            pending: None,
+            last_search_refresh: Instant::now(),
+            pending_searches: None,
+        }
+    }
+}
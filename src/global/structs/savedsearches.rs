@@ -0,0 +1,121 @@
+use crate::{config::Search, global::traits::DataFile};
+use serde::{Deserialize, Serialize};
+use typemap::Key;
+
+/// a search saved under a name, displayed as a feed page which highlights results that were not
+/// seen before
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+    pub search: Search,
+    /// ids of results that have been seen, the latest are pushed to the back
+    #[serde(default)]
+    pub seen: Vec<String>,
+    /// ids of results that were new when the feed is refreshed, and are not marked as read yet
+    #[serde(default)]
+    pub unread: Vec<String>,
+}
+
+impl SavedSearch {
+    /// number of seen ids to remember for each saved search
+    const MAX_SEEN: usize = 1000;
+
+    pub fn new(name: String, search: Search) -> Self {
+        Self {
+            name,
+            search: Search { page: 1, ..search },
+            seen: Vec::new(),
+            unread: Vec::new(),
+        }
+    }
+
+    /// updates seen and unread results with a newly fetched result list, returns ids of results
+    /// that were not seen before
+    // all results of the first refresh are treated as seen, so that saving a search does not
+    // flood the feed with new results
+    pub fn update(&mut self, ids: &[&str]) -> Vec<String> {
+        let first_refresh = self.seen.is_empty();
+        let new = ids
+            .iter()
+            .filter(|id| !self.seen.iter().any(|seen| seen == *id))
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+
+        self.seen.extend(new.iter().cloned());
+        if self.seen.len() > Self::MAX_SEEN {
+            self.seen.drain(..self.seen.len() - Self::MAX_SEEN);
+        }
+
+        if first_refresh {
+            return Vec::new();
+        }
+
+        // results that are no longer in the list cannot be marked as read
+        self.unread.retain(|id| ids.contains(&id.as_str()));
+        self.unread.extend(new.iter().cloned());
+        new
+    }
+
+    pub fn is_unread(&self, id: &str) -> bool {
+        self.unread.iter().any(|unread| unread == id)
+    }
+}
+
+/// named searches, shown as feed pages
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct SavedSearches(pub Vec<SavedSearch>);
+
+impl Key for SavedSearches {
+    type Value = Self;
+}
+
+impl DataFile for SavedSearches {
+    const PATH: &'static str = "saved_searches.json";
+}
+
+impl SavedSearches {
+    pub fn get(&self, name: &str) -> Option<&SavedSearch> {
+        self.0.iter().find(|saved| saved.name == name)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut SavedSearch> {
+        self.0.iter_mut().find(|saved| saved.name == name)
+    }
+
+    /// saves a search under a name, returns true if a saved search with the same name is replaced
+    pub fn insert(&mut self, name: String, search: Search) -> bool {
+        let replaced = self.remove(&name);
+        self.0.push(SavedSearch::new(name, search));
+        replaced
+    }
+
+    /// returns false if there are no saved searches with that name
+    pub fn remove(&mut self, name: &str) -> bool {
+        let original_len = self.0.len();
+        self.0.retain(|saved| saved.name != name);
+        self.0.len() != original_len
+    }
+
+    /// total number of unread results in all saved searches
+    pub fn count(&self) -> usize {
+        self.0.iter().map(|saved| saved.unread.len()).sum()
+    }
+
+    /// marks a result as read, returns false if the result is not unread
+    pub fn mark_read(&mut self, name: &str, id: &str) -> bool {
+        match self.get_mut(name) {
+            Some(saved) if saved.is_unread(id) => {
+                saved.unread.retain(|unread| unread != id);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// marks all results of a saved search as read
+    pub fn mark_all_read(&mut self, name: &str) {
+        if let Some(saved) = self.get_mut(name) {
+            saved.unread.clear();
+        }
+    }
+}
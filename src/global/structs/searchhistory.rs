@@ -0,0 +1,51 @@
+use crate::{config::Search, global::traits::DataFile};
+use serde::{Deserialize, Serialize};
+use typemap::Key;
+
+/// past searches with the filters used, the latest search is pushed to the back
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct SearchHistory(pub Vec<Search>);
+
+impl Key for SearchHistory {
+    type Value = Self;
+}
+
+impl DataFile for SearchHistory {
+    const PATH: &'static str = "search_history.json";
+}
+
+impl SearchHistory {
+    /// adds a search to the history, the same query with the same filters is moved to the top
+    /// instead of being added again
+    pub fn push(&mut self, search: &Search, max_length: usize) {
+        // pages are not remembered, searches are always reloaded from the first page
+        let search = Search {
+            page: 1,
+            ..search.clone()
+        };
+
+        self.0.retain(|past| past != &search);
+        self.0.push(search);
+
+        if self.0.len() > max_length {
+            self.0.drain(..self.0.len() - max_length);
+        }
+    }
+
+    /// returns past searches from the latest to the oldest, only the latest search of each query
+    /// is kept
+    pub fn recent(&self) -> Vec<&Search> {
+        let mut out: Vec<&Search> = Vec::new();
+        for search in self.0.iter().rev() {
+            if !out.iter().any(|seen| seen.query == search.query) {
+                out.push(search);
+            }
+        }
+        out
+    }
+
+    /// removes all past searches
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
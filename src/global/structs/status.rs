@@ -11,6 +11,8 @@ pub struct Status {
     pub popup_opened: bool,
     /// is search filter popup opened
     pub search_filter_opened: bool,
+    /// is the search suggestions dropdown opened
+    pub search_suggestions_opened: bool,
     /// to prevent rerendering the same image
     pub render_image: bool,
     /// the textfield for command capture
@@ -23,6 +25,9 @@ pub struct Status {
     pub provider: Provider,
     /// if provider is updated, lasts for 1 event loop
     pub provider_updated: bool,
+    /// if something is loading in the background, the screen is re-rendered periodically to show
+    /// its progress, lasts for 1 event loop
+    pub background_loading: bool,
 }
 
 impl Key for Status {
@@ -34,12 +39,14 @@ impl Default for Status {
         Self {
             popup_opened: false,
             search_filter_opened: false,
+            search_suggestions_opened: false,
             render_image: true,
             command_capture: None,
             exit: false,
             prev_frame: None,
             provider: Provider::YouTube,
             provider_updated: false,
+            background_loading: false,
         }
     }
 }
@@ -0,0 +1,23 @@
+use crate::global::{structs::Item, traits::Collection};
+use serde::{Deserialize, Serialize};
+use typemap::Key;
+
+/// subscribed channels, the subscriptions page displays the latest videos from them
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Subscriptions(pub Vec<Item>);
+
+impl Key for Subscriptions {
+    type Value = Self;
+}
+
+impl Collection for Subscriptions {
+    const INDEX_PATH: &'static str = "subscriptions.json";
+
+    fn items(&self) -> &Vec<Item> {
+        &self.0
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Item> {
+        &mut self.0
+    }
+}
@@ -1,12 +1,10 @@
 use crate::{
     config::*,
-    global::{functions::*, structs::*},
+    global::{functions::*, structs::*, traits::FrameworkBackend},
 };
 
-use std::{error::Error, io::Stdout, mem};
-use tui::{
-    backend::CrosstermBackend, layout::Alignment, style::Style, widgets::Paragraph, Frame, Terminal,
-};
+use std::{error::Error, mem};
+use tui::{layout::Alignment, style::Style, widgets::Paragraph, Frame, Terminal};
 use tui_additions::framework::{CursorState, Framework};
 use typemap::Key;
 
@@ -74,10 +72,10 @@ impl TaskQueue {
     }
 
     /// runs all tasks in a task queue
-    pub fn run(
+    pub fn run<B: FrameworkBackend>(
         mut self,
         framework: &mut Framework,
-        terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+        terminal: &mut Terminal<B>,
     ) -> Result<(), Box<dyn Error>> {
         // if there is any commands, run them first as they may modify data, which is rendered
         // later in this function
@@ -200,9 +198,9 @@ impl TaskQueue {
     }
 
     /// the render task runs this function
-    pub fn render(
+    pub fn render<B: FrameworkBackend>(
         framework: &mut Framework,
-        terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+        terminal: &mut Terminal<B>,
     ) -> Result<(), Box<dyn Error>> {
         terminal.draw(|frame| {
             Self::render_with_frame(framework, frame);
@@ -212,9 +210,9 @@ impl TaskQueue {
     }
 
     /// this function force clears the terminal before rendering, removing sixels and halfblock images
-    pub fn render_force_clear(
+    pub fn render_force_clear<B: FrameworkBackend>(
         framework: &mut Framework,
-        terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+        terminal: &mut Terminal<B>,
     ) -> Result<(), Box<dyn Error>> {
         terminal.clear()?;
         terminal.draw(|frame| {
@@ -225,10 +223,7 @@ impl TaskQueue {
     }
 
     /// this function renders onto the given frame
-    pub fn render_with_frame(
-        framework: &mut Framework,
-        frame: &mut Frame<CrosstermBackend<Stdout>>,
-    ) {
+    pub fn render_with_frame<B: FrameworkBackend>(framework: &mut Framework, frame: &mut Frame<B>) {
         let min_dimentions = framework.data.state.get::<MinDimentions>().unwrap();
         let area = frame.size();
 
@@ -263,7 +258,7 @@ impl TaskQueue {
             return;
         }
 
-        framework.render(frame);
+        B::render_framework(framework, frame);
     }
 }
 
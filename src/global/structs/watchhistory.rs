@@ -9,8 +9,15 @@ impl Key for WatchHistory {
     type Value = Self;
 }
 
+impl WatchHistory {
+    /// returns true if an item with the id is in the watch history
+    pub fn contains(&self, id: &str) -> bool {
+        self.0.iter().any(|item| item.id() == Some(id))
+    }
+}
+
 impl Collection for WatchHistory {
-    const INDEX_PATH: &'static str = ".local/share/youtube-tui/watch_history.json";
+    const INDEX_PATH: &'static str = "watch_history.json";
 
     fn items(&self) -> &Vec<Item> {
         &self.0
@@ -1,5 +1,7 @@
-use crate::global::structs::Item;
-use home::home_dir;
+use crate::global::{
+    functions::{cache_dir, data_dir},
+    structs::Item,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     error::Error,
@@ -27,7 +29,7 @@ where
                 .filter(|id| !id.is_empty())
                 .collect::<Vec<&str>>(),
         )?;
-        let path = home_dir().unwrap().join(Self::INDEX_PATH);
+        let path = data_dir().join(Self::INDEX_PATH);
 
         let mut file = OpenOptions::new()
             .write(true)
@@ -42,7 +44,7 @@ where
 
     /// add an item to watch history
     fn push(&mut self, item: Item, max_length: Option<usize>) -> Result<(), Box<dyn Error>> {
-        let info = home_dir().unwrap().join(".cache/youtube-tui/info/");
+        let info = cache_dir().join("info");
 
         // removes duplicates and place them on top (if exists)
         let id = item.id().unwrap_or("invalid-dump");
@@ -73,7 +75,7 @@ where
 
     /// loads watch history from file
     fn load() -> Vec<Item> {
-        let path = home_dir().unwrap().join(Self::INDEX_PATH);
+        let path = data_dir().join(Self::INDEX_PATH);
         let res = (|| -> Result<Vec<String>, Box<dyn Error>> {
             let file_string = fs::read_to_string(&path)?;
             let deserialized = serde_json::from_str(&file_string)?;
@@ -83,7 +85,7 @@ where
         // if res is err, then the file either doesn't exist of has be altered incorrectly, in
         // which case returns Self::default()
         if let Ok(deserialized) = res {
-            let info = home_dir().unwrap().join(".cache/youtube-tui/info/");
+            let info = cache_dir().join("info");
             deserialized
                 .into_iter()
                 .filter_map(|id| fs::read_to_string(info.join(format!("{id}.json"))).ok())
@@ -103,11 +105,10 @@ where
 
     /// moves thumbnails of videos in watch history from cache back to storage when exiting, so that thumbnails can be viewed offline
     fn exit_move(&self) {
-        let home_dir = home_dir().unwrap();
-        let store_thumbnails_path = home_dir.join(".local/share/youtube-tui/thumbnails/");
-        let store_info_path = home_dir.join(".local/share/youtube-tui/info/");
-        let cache_thumbnails_path = home_dir.join(".cache/youtube-tui/thumbnails/");
-        let cache_info_path = home_dir.join(".cache/youtube-tui/info");
+        let store_thumbnails_path = data_dir().join("thumbnails");
+        let store_info_path = data_dir().join("info");
+        let cache_thumbnails_path = cache_dir().join("thumbnails");
+        let cache_info_path = cache_dir().join("info");
 
         self.items().iter().for_each(|item| {
             let id = item.id().unwrap_or("invalid-dump");
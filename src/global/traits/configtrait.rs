@@ -1,12 +1,21 @@
-use home::home_dir;
 use serde::{de::DeserializeOwned, Serialize};
+use serde_yaml::Value;
 use std::{
     error::Error,
     fs::{self, OpenOptions},
     io::Write,
+    path::Path,
 };
 
-use crate::config::WriteConfig;
+use crate::{
+    config::WriteConfig,
+    global::{
+        functions::{
+            append_missing_keys, config_dir, merge_values, profile_label, split_values, update_keys,
+        },
+        structs::ConfigIssue,
+    },
+};
 
 pub const EXTENSION: &str = "yml";
 
@@ -18,64 +27,119 @@ pub trait ConfigTrait {
     where
         Self: Serialize + DeserializeOwned + Default + Clone,
     {
-        let home_dir = home_dir().unwrap();
-        let config_path =
-            home_dir.join(format!(".config/youtube-tui/{}.{}", Self::LABEL, EXTENSION));
+        let config_path = config_dir().join(format!("{}.{}", Self::LABEL, EXTENSION));
+
+        let content = fs::read_to_string(&config_path).ok();
 
         // The config struct
-        let config: Self = {
-            || -> Result<Self, Box<dyn Error>> {
-                let content = match fs::read_to_string(&config_path) {
-                    Ok(content) => content,
-                    // If the config file does not exist returns Self::defult()
-                    Err(_) => return Ok(Self::default()),
-                };
-
-                match serde_yaml::from_str::<Self>(&content) {
-                    Ok(config) => Ok(config),
-                    // If there is error parsing the json file, create a backup with current time and returns
-                    // Self::default()
-                    Err(_) => {
-                        let mut new_path = config_path.clone();
-                        new_path.pop();
-                        new_path.push(format!(
-                            "{}-{}.{}",
-                            Self::LABEL,
-                            chrono::offset::Local::now(),
-                            EXTENSION
-                        ));
-                        fs::rename(&config_path, &new_path)?;
-                        Ok(Self::default())
-                    }
-                }
-            }
-        }()?;
-
-        // Overwrites the old config file with added options (if any),
-        // but it also removes things like comments in the old config file
-
-        match write {
-            WriteConfig::Must => {
-                let mut file = OpenOptions::new()
-                    .write(true)
-                    .create(true)
-                    .truncate(true)
-                    .open(&config_path)?;
-                file.write_all(serde_yaml::to_string(&config)?.as_bytes())?
-            }
-            WriteConfig::Try => {
-                if let Ok(mut file) = OpenOptions::new()
-                    .write(true)
-                    .create(true)
-                    .truncate(true)
-                    .open(&config_path)
-                {
-                    let _ = file.write_all(serde_yaml::to_string(&config)?.as_bytes());
+        let config: Self = match &content {
+            // the file is left untouched if it cannot be parsed, so that it can be fixed
+            Some(content) => serde_yaml::from_str(content)
+                .map_err(|e| ConfigIssue::from_yaml(Self::LABEL, &e))?,
+            // If the config file does not exist returns Self::defult()
+            None => Self::default(),
+        };
+
+        // Adds options missing in the config file (if any), comments and everything else in
+        // the file are kept
+        let new_content = match &content {
+            Some(content) => append_missing_keys(content, &serde_yaml::to_value(&config)?)
+                .unwrap_or_else(|| content.clone()),
+            None => serde_yaml::to_string(&config)?,
+        };
+
+        if content.as_ref() != Some(&new_content) {
+            match write {
+                WriteConfig::Must => write_file(&config_path, &new_content)?,
+                WriteConfig::Try => {
+                    let _ = write_file(&config_path, &new_content);
                 }
+                WriteConfig::Dont => {}
             }
-            WriteConfig::Dont => {}
         }
 
+        // options in the current profile replace the ones in the config file
+        let Some((profile_label, overlay)) = profile_overlay(Self::LABEL)? else {
+            return Ok(Box::new(config));
+        };
+        let mut value = serde_yaml::to_value(&config)?;
+        merge_values(&mut value, overlay);
+        // parsed from text so that the error contains the key, the location is found again in the
+        // profile
+        let config = serde_yaml::from_str(&serde_yaml::to_string(&value)?).map_err(|e| {
+            ConfigIssue {
+                location: None,
+                ..ConfigIssue::from_yaml(&profile_label, &e)
+            }
+            .in_config(&profile_label)
+        })?;
+
         Ok(Box::new(config))
     }
+
+    /// writes the current values to the config file, only options that are changed are replaced,
+    /// options in the current profile are saved to the profile
+    fn save(&self) -> Result<(), Box<dyn Error>>
+    where
+        Self: Serialize,
+    {
+        let config_path = config_dir().join(format!("{}.{}", Self::LABEL, EXTENSION));
+        let value = serde_yaml::to_value(self)?;
+
+        let Some((profile_label, overlay)) = profile_overlay(Self::LABEL)? else {
+            return write_value(&config_path, &value);
+        };
+        let (rest, overlaid) = split_values(value, &overlay);
+        write_value(
+            &config_dir().join(format!("{profile_label}.{EXTENSION}")),
+            &overlaid,
+        )?;
+
+        // options which are only in the profile are left alone in the config file
+        let mut base = fs::read_to_string(&config_path)
+            .ok()
+            .and_then(|content| serde_yaml::from_str(&content).ok())
+            .unwrap_or(Value::Null);
+        merge_values(&mut base, rest);
+        write_value(&config_path, &base)
+    }
+}
+
+/// the label and content of the file overlaying `label` in the current profile, if there is one
+fn profile_overlay(label: &str) -> Result<Option<(String, Value)>, ConfigIssue> {
+    let Some(profile_label) = profile_label(label) else {
+        return Ok(None);
+    };
+    let Ok(content) = fs::read_to_string(config_dir().join(format!("{profile_label}.{EXTENSION}")))
+    else {
+        return Ok(None);
+    };
+
+    let overlay =
+        serde_yaml::from_str(&content).map_err(|e| ConfigIssue::from_yaml(&profile_label, &e))?;
+    Ok(Some((profile_label, overlay)))
+}
+
+/// replaces options that are changed in the file with the ones in `value`
+fn write_value(path: &Path, value: &Value) -> Result<(), Box<dyn Error>> {
+    let content = match fs::read_to_string(path)
+        .ok()
+        .and_then(|content| update_keys(&content, value))
+    {
+        Some(content) => content,
+        None => serde_yaml::to_string(value)?,
+    };
+
+    write_file(path, &content)
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), Box<dyn Error>> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    file.write_all(content.as_bytes())?;
+
+    Ok(())
 }
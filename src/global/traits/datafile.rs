@@ -0,0 +1,37 @@
+use crate::global::functions::data_dir;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    error::Error,
+    fs::{self, OpenOptions},
+    io::Write,
+};
+
+/// Trait for loading, saving state stored as a json file in the data directory
+pub trait DataFile
+where
+    Self: Default + Serialize + DeserializeOwned,
+{
+    /// path of the file relative to the data directory
+    const PATH: &'static str;
+
+    /// loads from file, returns `Self::default()` if the file is invalid
+    fn load() -> Self {
+        let path = data_dir().join(Self::PATH);
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// saves to file
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(data_dir().join(Self::PATH))?;
+        file.write_all(serde_json::to_string_pretty(self)?.as_bytes())?;
+
+        Ok(())
+    }
+}
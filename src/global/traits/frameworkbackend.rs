@@ -0,0 +1,54 @@
+use std::io::Stdout;
+use tui::{
+    backend::{Backend, CrosstermBackend},
+    Frame,
+};
+use tui_additions::framework::Framework;
+
+/// backends that the framework can be rendered onto
+pub trait FrameworkBackend: Backend + Sized {
+    fn render_framework(framework: &mut Framework, frame: &mut Frame<Self>);
+}
+
+impl FrameworkBackend for CrosstermBackend<Stdout> {
+    fn render_framework(framework: &mut Framework, frame: &mut Frame<Self>) {
+        framework.render(frame);
+    }
+}
+
+// framework items can only render onto a crossterm frame, so in tests they are rendered onto an
+// offscreen terminal (which is never flushed to stdout) and the buffer is copied over
+#[cfg(test)]
+impl FrameworkBackend for tui::backend::TestBackend {
+    fn render_framework(framework: &mut Framework, frame: &mut Frame<Self>) {
+        use tui::{
+            buffer::Buffer, layout::Rect, widgets::Widget, Terminal, TerminalOptions, Viewport,
+        };
+
+        /// a widget that copies the content of another buffer onto the frame
+        struct BufferCopy<'a>(&'a Buffer);
+
+        impl Widget for BufferCopy<'_> {
+            fn render(self, area: Rect, buf: &mut Buffer) {
+                let area = area.intersection(*self.0.area());
+                for y in area.top()..area.bottom() {
+                    for x in area.left()..area.right() {
+                        *buf.get_mut(x, y) = self.0.get(x, y).clone();
+                    }
+                }
+            }
+        }
+
+        let area = frame.size();
+        let Ok(mut offscreen) = Terminal::with_options(
+            CrosstermBackend::new(std::io::stdout()),
+            TerminalOptions {
+                viewport: Viewport::fixed(area),
+            },
+        ) else {
+            return;
+        };
+        framework.render(&mut offscreen.get_frame());
+        frame.render_widget(BufferCopy(offscreen.current_buffer_mut()), area);
+    }
+}
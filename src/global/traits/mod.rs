@@ -2,9 +2,13 @@
 mod asurlstring;
 mod collection;
 mod configtrait;
+mod datafile;
+mod frameworkbackend;
 mod searchfilteritem;
 
 pub use asurlstring::*;
 pub use collection::*;
 pub use configtrait::*;
+pub use datafile::*;
+pub use frameworkbackend::*;
 pub use searchfilteritem::*;
@@ -0,0 +1,443 @@
+//! renders pages onto a `TestBackend` instead of the terminal, so that layouts can be
+//! snapshot-tested without touching stdout, the user's files or the network
+use crate::{
+    config::*,
+    global::{functions::*, structs::*},
+    init_data, load_configs,
+};
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{Read, Write},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    process,
+    sync::{Mutex, MutexGuard},
+    thread,
+};
+use tui::{backend::TestBackend, style::Color, Terminal};
+use tui_additions::framework::{Framework, State};
+
+/// where fixtures and snapshots are stored
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+// envs are process wide, so pages are loaded one at a time
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub struct Headless {
+    pub framework: Framework,
+    pub terminal: Terminal<TestBackend>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+/// points the config, cache and data directories to a temporary directory, which is used by one
+/// test at a time until the guard is dropped
+pub fn lock_dirs() -> MutexGuard<'static, ()> {
+    let lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let dir = env::temp_dir().join(format!("youtube-tui-test-{}", process::id()));
+    set_config_dir(dir.join("config"));
+    set_cache_dir(dir.join("cache"));
+    set_data_dir(dir.join("data"));
+
+    lock
+}
+
+/// starts a server on localhost which replies to every request with `body`, returns the url of
+/// the server
+pub fn serve(body: String) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let server = format!("http://{}", listener.local_addr().unwrap());
+
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let _ = stream.read(&mut [0; 4096]);
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+
+    server
+}
+
+impl Headless {
+    /// creates a framework with the same data as `init()`, but using default configs, empty
+    /// collections and no images, files are written to a temporary directory
+    pub fn new(width: u16, height: u16) -> Result<Self, Box<dyn Error>> {
+        let lock = lock_dirs();
+        fs::create_dir_all(cache_dir().join("info"))?;
+        fs::create_dir_all(data_dir())?;
+
+        let mut framework = Framework::new(State(Vec::new()));
+        let main_config = MainConfig {
+            images: Images::None,
+            write_config: WriteConfig::Dont,
+            ..Default::default()
+        };
+
+        framework
+            .data
+            .global
+            .insert::<InvidiousClient>(InvidiousClient::new(
+                main_config.invidious_instance.clone(),
+            ));
+        framework
+            .data
+            .global
+            .insert::<CommandsConfig>(CommandsConfig::from(CommandsConfigSerde::default()));
+        framework
+            .data
+            .global
+            .insert::<AppearanceConfig>(AppearanceConfig::from(AppearanceConfigSerde::default())?);
+        framework
+            .data
+            .global
+            .insert::<PagesConfig>(PagesConfig::default());
+        framework
+            .data
+            .global
+            .insert::<KeyBindingsConfig>(KeyBindingsConfigSerde::default().into()?);
+        framework
+            .data
+            .global
+            .insert::<CommandBindings>(CommandBindingsSerde::default().into()?);
+        framework
+            .data
+            .global
+            .insert::<FiltersConfig>(FiltersConfig::default());
+        framework.data.state.insert::<Search>(Search::default());
+        framework.data.global.insert::<MainConfig>(main_config);
+
+        framework
+            .data
+            .global
+            .insert::<WatchHistory>(WatchHistory::default());
+        framework.data.global.insert::<Library>(Library::default());
+        framework
+            .data
+            .global
+            .insert::<Subscriptions>(Subscriptions::default());
+        framework
+            .data
+            .global
+            .insert::<LastSeen>(LastSeen::default());
+        framework
+            .data
+            .global
+            .insert::<SearchHistory>(SearchHistory::default());
+        framework
+            .data
+            .global
+            .insert::<SavedSearches>(SavedSearches::default());
+        framework
+            .data
+            .global
+            .insert::<ConfigWatcher>(ConfigWatcher::default());
+        init_data(&mut framework.split_clean().0);
+
+        Ok(Self {
+            framework,
+            terminal: Terminal::new(TestBackend::new(width, height))?,
+            _lock: lock,
+        })
+    }
+
+    /// path to a file in `tests/fixtures/`
+    pub fn fixture(name: &str) -> PathBuf {
+        Path::new(FIXTURES).join(name)
+    }
+
+    /// reads a json array of items, in the same format as the files in `${cache-dir}/info/`
+    pub fn load_fixture(name: &str) -> Result<Vec<Item>, Box<dyn Error>> {
+        Ok(serde_json::from_str(&fs::read_to_string(Self::fixture(
+            name,
+        ))?)?)
+    }
+
+    /// puts an item in the cache, so that its page is loaded without the network
+    pub fn cache_item(&self, id: &str, name: &str) -> Result<(), Box<dyn Error>> {
+        fs::copy(
+            Self::fixture(name),
+            cache_dir().join(format!("info/{id}.json")),
+        )?;
+        Ok(())
+    }
+
+    /// replies to every request to the Invidious instance with the content of a fixture
+    pub fn serve_fixture(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
+        let server = serve(fs::read_to_string(Self::fixture(name))?);
+        self.framework
+            .data
+            .global
+            .insert::<InvidiousClient>(InvidiousClient::new(server));
+        Ok(())
+    }
+
+    /// replaces items in the library, the last item is displayed on top
+    pub fn set_library(&mut self, items: Vec<Item>) {
+        self.framework.data.global.insert::<Library>(Library(items));
+    }
+
+    /// replaces the watch history, as if the items were watched in the app
+    pub fn set_watch_history(&mut self, items: Vec<Item>) {
+        self.framework
+            .data
+            .global
+            .insert::<WatchHistory>(WatchHistory(items));
+        self.framework
+            .data
+            .global
+            .get_mut::<LabelsRevision>()
+            .unwrap()
+            .bump();
+    }
+
+    /// loads and renders a page, the same way as running `loadpage` in the app
+    pub fn load_page(&mut self, page: Page) -> Result<(), Box<dyn Error>> {
+        let mut queue = TaskQueue::default();
+        queue.push(Task::LoadPage(page));
+        queue.run(&mut self.framework, &mut self.terminal)
+    }
+
+    /// the smallest size the current page can be displayed in
+    pub fn min_size(&self) -> (u16, u16) {
+        let min = self.framework.data.state.get::<MinDimentions>().unwrap();
+        (min.width, min.height)
+    }
+
+    /// changes the size of the test terminal, takes effect in the next `snapshot()`
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.terminal.backend_mut().resize(width, height);
+    }
+
+    /// renders the current state and returns the symbols of the buffer, one line per row
+    pub fn snapshot(&mut self) -> Result<String, Box<dyn Error>> {
+        TaskQueue::render(&mut self.framework, &mut self.terminal)?;
+        let buffer = self.terminal.backend().buffer();
+        let area = buffer.area();
+
+        Ok((area.top()..area.bottom())
+            .map(|y| {
+                (area.left()..area.right())
+                    .map(|x| buffer.get(x, y).symbol.as_str())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+/// compares with `tests/fixtures/snapshots/{name}.txt`, run with `UPDATE_SNAPSHOTS=1` to
+/// replace the saved snapshots
+fn assert_snapshot(name: &str, snapshot: &str) {
+    let path = Headless::fixture(&format!("snapshots/{name}.txt"));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, format!("{snapshot}\n")).unwrap();
+        return;
+    }
+
+    let saved =
+        fs::read_to_string(&path).unwrap_or_else(|_| panic!("missing snapshot {}", path.display()));
+    assert_eq!(
+        saved.trim_end_matches('\n'),
+        snapshot,
+        "snapshot {name} does not match"
+    );
+}
+
+/// snapshots of the page at 80x24, 120x40 and its minimum size
+fn assert_snapshots(headless: &mut Headless, name: &str) -> Result<(), Box<dyn Error>> {
+    let (min_width, min_height) = headless.min_size();
+    for (width, height) in [(80, 24), (120, 40), (min_width, min_height)] {
+        headless.resize(width, height);
+        let snapshot = headless.snapshot()?;
+        assert_snapshot(&format!("{name}_{width}x{height}"), &snapshot);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn main_menu() -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(80, 24)?;
+    headless.set_library(Headless::load_fixture("library.json")?);
+    headless.load_page(Page::MainMenu(MainMenuPage::Library))?;
+    assert_snapshots(&mut headless, "main_menu")
+}
+
+#[test]
+fn search_results() -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(80, 24)?;
+    headless.serve_fixture("search.json")?;
+    headless.load_page(Page::Search(Search {
+        query: String::from("rust tui"),
+        ..Default::default()
+    }))?;
+    assert_snapshots(&mut headless, "search")
+}
+
+#[test]
+fn video_page() -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(80, 24)?;
+    headless.cache_item("dQw4w9WgXcQ", "video.json")?;
+    headless.load_page(Page::SingleItem(SingleItemPage::Video(
+        String::from("dQw4w9WgXcQ"),
+        None,
+    )))?;
+    assert_snapshots(&mut headless, "video")
+}
+
+#[test]
+fn channel_page() -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(80, 24)?;
+    headless.serve_fixture("channel.json")?;
+    headless.load_page(Page::ChannelDisplay(ChannelDisplayPage {
+        id: String::from("UCaaaaaaaaaaaaaaaaaaaaaa"),
+        r#type: ChannelDisplayPageType::Main,
+        sort: ChannelSort::default(),
+        query: String::new(),
+    }))?;
+    assert_snapshots(&mut headless, "channel")
+}
+
+#[test]
+fn empty_channel_tab() -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(80, 24)?;
+    headless.serve_fixture("channel_empty_tab.json")?;
+    headless.load_page(Page::ChannelDisplay(ChannelDisplayPage {
+        id: String::from("UCaaaaaaaaaaaaaaaaaaaaaa"),
+        r#type: ChannelDisplayPageType::Shorts,
+        sort: ChannelSort::default(),
+        query: String::new(),
+    }))?;
+    assert_snapshot("channel_empty_tab_80x24", &headless.snapshot()?);
+    Ok(())
+}
+
+#[test]
+fn watched_marker() -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(80, 24)?;
+    let library = Headless::load_fixture("library.json")?;
+    headless.set_library(library.clone());
+    headless.load_page(Page::MainMenu(MainMenuPage::Library))?;
+    assert!(!headless.snapshot()?.contains("[watched]"));
+
+    // labels are updated without reloading the page
+    headless.set_watch_history(vec![library[1].clone()]);
+    let snapshot = headless.snapshot()?;
+    assert!(snapshot.contains("[watched] Writing a TUI in Rust"));
+    assert!(!snapshot.contains("[watched] Ratatui tutorials"));
+
+    // watched rows are coloured with `colors.watched`
+    let y = snapshot
+        .lines()
+        .position(|line| line.contains("[watched] Writing a TUI in Rust"))
+        .unwrap() as u16;
+    let buffer = headless.terminal.backend().buffer();
+    assert_eq!(buffer.get(2, y).fg, Color::DarkGray);
+    assert_ne!(buffer.get(2, y + 1).fg, Color::DarkGray);
+    Ok(())
+}
+
+#[test]
+fn below_minimum_size() -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(80, 24)?;
+    headless.load_page(Page::MainMenu(MainMenuPage::Library))?;
+    let (min_width, min_height) = headless.min_size();
+    headless.resize(min_width - 1, min_height);
+
+    let snapshot = headless.snapshot()?;
+    assert!(snapshot.contains(&format!("Current: {} x {min_height}", min_width - 1)));
+    assert!(snapshot.contains(&format!("Required: {min_width} x {min_height}")));
+    Ok(())
+}
+
+#[test]
+fn search_filter_labels() -> Result<(), Box<dyn Error>> {
+    use crate::items::SearchFilter;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tui_additions::framework::{FrameworkItem, ItemInfo};
+
+    let mut headless = Headless::new(80, 24)?;
+    let filters = &mut headless
+        .framework
+        .data
+        .state
+        .get_mut::<Search>()
+        .unwrap()
+        .filters;
+    filters.features.toggle(SearchFilterFeature::Hd);
+    filters.region = SearchFilterRegion(Some(String::from("XX")));
+    let info = ItemInfo {
+        selected: true,
+        hover: false,
+        x: 0,
+        y: 0,
+    };
+    let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+
+    // reloading with the cursor on features keeps the selected features marked
+    let mut filter = SearchFilter::default();
+    filter.left_textlist.selected = 4;
+    filter.load_item(&mut headless.framework.split_clean().0, info)?;
+    assert_eq!(filter.right_textlist.items[..2], ["[x] HD", "[ ] 4K"]);
+
+    // a region that is not in the list is shown as it is
+    filter.key_event(&mut headless.framework.split_clean().0, down, info)?;
+    assert_eq!(filter.right_textlist.items.last().unwrap(), "XX");
+    assert_eq!(
+        filter.right_textlist.selected,
+        filter.right_textlist.items.len() - 1
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_blocked_patterns() -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(80, 24)?;
+    let _ = fs::remove_dir_all(config_dir());
+    fs::create_dir_all(config_dir())?;
+    fs::write(
+        config_dir().join("filters.yml"),
+        "blocked_patterns:\n- rust\n- (unclosed\n",
+    )?;
+
+    // reported at launch, the valid patterns are still used
+    let mut issues = Vec::new();
+    load_configs(&mut headless.framework.split_clean().0, Some(&mut issues))?;
+    assert_eq!(
+        issues.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        ["filters.yml:1:1: `blocked_patterns`: `(unclosed` is not a valid regular expression"]
+    );
+    let filters = headless
+        .framework
+        .data
+        .global
+        .get::<FiltersConfig>()
+        .unwrap();
+    assert_eq!(filters.regexes.len(), 1);
+
+    // reloads are rejected, the current filters are kept
+    fs::write(
+        config_dir().join("filters.yml"),
+        "blocked_patterns:\n- (unclosed\n",
+    )?;
+    let res = load_configs(&mut headless.framework.split_clean().0, None);
+    let _ = fs::remove_dir_all(config_dir());
+    assert!(res.is_err());
+    let filters = headless
+        .framework
+        .data
+        .global
+        .get::<FiltersConfig>()
+        .unwrap();
+    assert_eq!(filters.blocked_patterns, ["rust", "(unclosed"]);
+    Ok(())
+}
@@ -1,35 +1,33 @@
 use crate::{
     config::*,
     global::{functions::*, structs::*, traits::*},
+    CliArgs,
 };
-use home::home_dir;
-use std::{error::Error, fs, io::Stdout};
-use tui::{backend::CrosstermBackend, Terminal};
+use std::{env, error::Error, fs};
+use tui::Terminal;
 use tui_additions::framework::{Framework, FrameworkClean};
 
 /// app to run before the app starts
 // init tasks:
 //  - create folders like `~/.config/youtube-tui/` and `~/.cache/youtube-tui/thumbnails/`
+//  - set the `config-dir`, `cache-dir` and `data-dir` envs
 //  - load all config files
 //  - insert data
-pub fn init(
+pub fn init<B: FrameworkBackend>(
     framework: &mut Framework,
-    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
-    command: Option<&str>,
+    terminal: &mut Terminal<B>,
+    cli: CliArgs,
 ) -> Result<(), Box<dyn Error>> {
-    let home_dir = home_dir().unwrap();
-
     // creating files
     [
-        ".cache/youtube-tui/thumbnails/",
-        ".cache/youtube-tui/info/",
-        ".local/share/youtube-tui/thumbnails/",
-        ".local/share/youtube-tui/info/",
-        ".local/share/youtube-tui/saved/",
+        cache_dir().join("thumbnails"),
+        cache_dir().join("info"),
+        data_dir().join("thumbnails"),
+        data_dir().join("info"),
+        data_dir().join("saved"),
     ]
     .into_iter()
-    .for_each(|s| {
-        let dir = home_dir.join(s);
+    .for_each(|dir| {
         if !dir.exists() {
             fs::create_dir_all(dir).unwrap();
         }
@@ -37,7 +35,15 @@ pub fn init(
 
     init_move();
 
-    load_configs(&mut framework.split_clean().0)?;
+    // set before anything else, so they are kept when envs of a page are cleared
+    env::set_var("config-dir", config_dir());
+    env::set_var("cache-dir", cache_dir());
+    env::set_var("data-dir", data_dir());
+
+    framework.data.global.insert::<CliArgs>(cli);
+    // the TUI still launches with invalid config files, which are reported in the message bar
+    let mut issues = Vec::new();
+    load_configs(&mut framework.split_clean().0, Some(&mut issues))?;
 
     framework
         .data
@@ -47,6 +53,59 @@ pub fn init(
         .data
         .global
         .insert::<Library>(Library(Library::load()));
+    framework
+        .data
+        .global
+        .insert::<Subscriptions>(Subscriptions(Subscriptions::load()));
+    framework.data.global.insert::<LastSeen>(LastSeen::load());
+    framework
+        .data
+        .global
+        .insert::<SearchHistory>(SearchHistory::load());
+    framework
+        .data
+        .global
+        .insert::<SavedSearches>(SavedSearches::load());
+    init_data(&mut framework.split_clean().0);
+    #[cfg(unix)]
+    start_control_socket(framework);
+
+    let command = framework
+        .data
+        .global
+        .get::<CliArgs>()
+        .unwrap()
+        .startup_command(framework.data.global.get::<CommandsConfig>().unwrap());
+    run_command(&command, framework, terminal);
+
+    // loading the startup page clears the message bar, so invalid config files (which are
+    // replaced by the defaults) and patterns are reported after it is loaded
+    // after it is loaded
+    if let Some(issue) = issues.first() {
+        while let Some(tasks) = framework.data.state.get_mut::<Tasks>().unwrap().pop() {
+            tasks.run(framework, terminal)?;
+        }
+        *framework.data.global.get_mut::<Message>().unwrap() = Message::Error(format!(
+            "Invalid config files, {issue}{}",
+            match issues.len() {
+                1 => String::new(),
+                len => format!(" (and {} more, run `youtube-tui check-config`)", len - 1),
+            }
+        ));
+        framework
+            .data
+            .state
+            .get_mut::<Tasks>()
+            .unwrap()
+            .priority
+            .push(Task::RenderAll);
+    }
+    Ok(())
+}
+
+/// insert data that does not come from config files or saved collections, requires `MainConfig`
+/// to be already inserted
+pub fn init_data(framework: &mut FrameworkClean) {
     framework.data.global.insert::<Message>(Message::None);
     framework.data.global.insert::<Status>(Status {
         provider: framework.data.global.get::<MainConfig>().unwrap().provider,
@@ -63,67 +122,148 @@ pub fn init(
         .data
         .state
         .insert::<StateEnvs>(StateEnvs::default());
-
-    run_command(
-        command.unwrap_or(
-            &framework
-                .data
-                .global
-                .get::<CommandsConfig>()
-                .unwrap()
-                .launch_command
-                .clone(),
-        ),
-        framework,
-        terminal,
-    );
-    Ok(())
+    framework
+        .data
+        .global
+        .insert::<Refresher>(Refresher::default());
+    framework
+        .data
+        .global
+        .insert::<HideWatched>(HideWatched::default());
+    framework
+        .data
+        .global
+        .insert::<LabelsRevision>(LabelsRevision::default());
 }
 
-/// reload all config files
-pub fn load_configs(framework: &mut FrameworkClean) -> Result<(), Box<dyn Error>> {
-    let home_dir = home_dir().unwrap();
-    let config_path = home_dir.join(".config/youtube-tui/");
+/// reload all config files, invalid files are an error and nothing is changed, unless `issues`
+/// is given, then the defaults are used for them and the problems are added to `issues`
+pub fn load_configs(
+    framework: &mut FrameworkClean,
+    mut issues: Option<&mut Vec<ConfigIssue>>,
+) -> Result<(), Box<dyn Error>> {
+    let config_path = config_dir();
 
     if !&config_path.exists() {
         fs::create_dir_all(&config_path).unwrap();
     }
 
-    // inserting data
-    let main_config = *MainConfig::load(WriteConfig::Try)?;
+    migrate_configs()?;
 
+    // everything is loaded before inserting, so that the current configs are kept if any of the
+    // files is invalid
+    let mut main_config = or_default(
+        MainConfig::LABEL,
+        MainConfig::load(WriteConfig::Try).map(|config| *config),
+        &mut issues,
+        || Ok(MainConfig::default()),
+    )?;
+    if let Some(cli) = framework.data.global.get::<CliArgs>() {
+        cli.apply(&mut main_config);
+    }
+    let write = main_config.write_config;
+
+    let commands = or_default(
+        CommandsConfigSerde::LABEL,
+        CommandsConfigSerde::load(write).map(|config| CommandsConfig::from(*config)),
+        &mut issues,
+        || Ok(CommandsConfig::from(CommandsConfigSerde::default())),
+    )?;
+    let appearance = or_default(
+        AppearanceConfigSerde::LABEL,
+        AppearanceConfig::load(write),
+        &mut issues,
+        || Ok(AppearanceConfig::from(AppearanceConfigSerde::default())?),
+    )?;
+    let pages = or_default(
+        PagesConfig::LABEL,
+        PagesConfig::load(write).map(|config| *config),
+        &mut issues,
+        || Ok(PagesConfig::default()),
+    )?;
+    let keybindings = or_default(
+        KeyBindingsConfigSerde::LABEL,
+        KeyBindingsConfig::load(write),
+        &mut issues,
+        || Ok(KeyBindingsConfigSerde::default().into()?),
+    )?;
+    let commandbindings = or_default(
+        CommandBindingsSerde::LABEL,
+        CommandBindingsSerde::load(write).and_then(|config| {
+            Ok((*config)
+                .into()
+                .map_err(|issue| issue.in_config(CommandBindingsSerde::LABEL))?)
+        }),
+        &mut issues,
+        || Ok(CommandBindingsSerde::default().into()?),
+    )?;
+    let mut filters = or_default(
+        FiltersConfig::LABEL,
+        FiltersConfig::load(write).map(|config| *config),
+        &mut issues,
+        || Ok(FiltersConfig::default()),
+    )?;
+    // invalid patterns are skipped at launch, but a reload is rejected like an invalid file so
+    // that the current filters are kept until the patterns are fixed
+    for issue in filters.compile() {
+        match &mut issues {
+            Some(issues) => issues.push(issue),
+            None => return Err(issue.into()),
+        }
+    }
+    let search = or_default(
+        Search::LABEL,
+        Search::load(write).map(|config| *config),
+        &mut issues,
+        || Ok(Search::default()),
+    )?;
+
+    // inserting data
     framework
         .data
         .global
         .insert::<InvidiousClient>(InvidiousClient::new(main_config.invidious_instance.clone()));
+    framework.data.global.insert::<CommandsConfig>(commands);
+    framework.data.global.insert::<AppearanceConfig>(appearance);
+    framework.data.global.insert::<PagesConfig>(pages);
     framework
         .data
         .global
-        .insert::<CommandsConfig>(CommandsConfig::from(*CommandsConfigSerde::load(
-            main_config.write_config,
-        )?));
+        .insert::<KeyBindingsConfig>(keybindings);
     framework
         .data
         .global
-        .insert::<AppearanceConfig>(AppearanceConfig::load(main_config.write_config)?);
-    framework
-        .data
-        .global
-        .insert::<PagesConfig>(*PagesConfig::load(main_config.write_config)?);
+        .insert::<CommandBindings>(commandbindings);
+    framework.data.global.insert::<FiltersConfig>(filters);
+    framework.data.state.insert::<Search>(search);
+    framework.data.global.insert::<MainConfig>(main_config);
+    // labels of item lists include `watched_marker`
+    if let Some(revision) = framework.data.global.get_mut::<LabelsRevision>() {
+        revision.bump();
+    }
+    // files written while loading are not changes to reload
     framework
         .data
         .global
-        .insert::<KeyBindingsConfig>(KeyBindingsConfig::load(main_config.write_config)?);
-    framework.data.global.insert::<CommandBindings>(
-        (*CommandBindingsSerde::load(main_config.write_config)?)
-            .into()
-            .unwrap(),
-    );
-    framework
-        .data
-        .state
-        .insert::<Search>(*Search::load(main_config.write_config)?);
-    framework.data.global.insert::<MainConfig>(main_config);
+        .insert::<ConfigWatcher>(ConfigWatcher::new());
 
     Ok(())
 }
+
+/// uses the default config if the file is invalid and `issues` is given, the problem is then added
+/// to `issues`
+fn or_default<T>(
+    label: &str,
+    res: Result<T, Box<dyn Error>>,
+    issues: &mut Option<&mut Vec<ConfigIssue>>,
+    default: impl FnOnce() -> Result<T, Box<dyn Error>>,
+) -> Result<T, Box<dyn Error>> {
+    match (res, issues) {
+        (Ok(config), _) => Ok(config),
+        (Err(e), Some(issues)) => {
+            issues.push(ConfigIssue::from_error(label, e));
+            default()
+        }
+        (Err(e), None) => Err(e),
+    }
+}
@@ -1,3 +1,5 @@
+use std::error::Error;
+
 use super::ItemInfo;
 use crate::{
     config::*,
@@ -13,19 +15,22 @@ use tui_additions::{
     widgets::{Grid, TextList},
 };
 
-/// the 4 pages that a channel has (including the default "blank" page when loading)
+/// the pages that a channel has (including the default "blank" page when loading)
 #[derive(Clone)]
 pub enum ChannelDisplay {
     /// a blank item, will turn into one of the other variants when `.load()` depending on the page
     None,
     /// main channel display page
     Main { channel: Item, iteminfo: ItemInfo },
-    /// latest videos
+    /// latest videos, also used for shorts and streams
     Videos {
         videos: Vec<Item>,
         textlist: TextList,
         iteminfo: ItemInfo,
         grid: Grid,
+        /// continuation token of the next batch of videos, a `Item::LoadMore` is at the end of
+        /// the list if this is some
+        continuation: Option<String>,
     },
     /// created playlists
     Playlists {
@@ -33,6 +38,15 @@ pub enum ChannelDisplay {
         textlist: TextList,
         iteminfo: ItemInfo,
         grid: Grid,
+        continuation: Option<String>,
+    },
+    /// community posts
+    Community {
+        posts: Vec<Item>,
+        textlist: TextList,
+        iteminfo: ItemInfo,
+        grid: Grid,
+        continuation: Option<String>,
     },
 }
 
@@ -53,14 +67,16 @@ impl ChannelDisplay {
                 videos, textlist, ..
             } => vec![(
                 String::from("hover-url"),
-                format!(
-                    "{}/watch?v={}",
-                    match status.provider {
-                        Provider::YouTube => "https://youtube.com",
-                        Provider::Invidious => &mainconfig.invidious_instance,
-                    },
-                    videos[textlist.selected].id().unwrap_or_default()
-                ),
+                match videos.get(textlist.selected).and_then(Item::id) {
+                    Some(id) => format!(
+                        "{}/watch?v={id}",
+                        match status.provider {
+                            Provider::YouTube => "https://youtube.com",
+                            Provider::Invidious => &mainconfig.invidious_instance,
+                        },
+                    ),
+                    None => String::from("not avaliable"),
+                },
             )],
             ChannelDisplay::Playlists {
                 playlists,
@@ -68,14 +84,31 @@ impl ChannelDisplay {
                 ..
             } => vec![(
                 String::from("hover-url"),
-                format!(
-                    "{}/playlist?list={}",
-                    match status.provider {
-                        Provider::YouTube => "https://youtube.com",
-                        Provider::Invidious => &mainconfig.invidious_instance,
-                    },
-                    playlists[textlist.selected].id().unwrap_or_default()
-                ),
+                match playlists.get(textlist.selected).and_then(Item::id) {
+                    Some(id) => format!(
+                        "{}/playlist?list={id}",
+                        match status.provider {
+                            Provider::YouTube => "https://youtube.com",
+                            Provider::Invidious => &mainconfig.invidious_instance,
+                        },
+                    ),
+                    None => String::from("not avaliable"),
+                },
+            )],
+            ChannelDisplay::Community {
+                posts, textlist, ..
+            } => vec![(
+                String::from("hover-url"),
+                match posts.get(textlist.selected).and_then(Item::id) {
+                    Some(id) => format!(
+                        "{}/post/{id}",
+                        match status.provider {
+                            Provider::YouTube => "https://youtube.com",
+                            Provider::Invidious => &mainconfig.invidious_instance,
+                        },
+                    ),
+                    None => String::from("not avaliable"),
+                },
             )],
             _ => Vec::new(),
         }
@@ -106,7 +139,8 @@ impl ChannelDisplay {
                         .set_cursor_style(Style::default().fg(appearance.colors.outline_secondary));
                 }
             }
-            ChannelDisplay::Playlists { textlist, grid, .. } => {
+            ChannelDisplay::Playlists { textlist, grid, .. }
+            | ChannelDisplay::Community { textlist, grid, .. } => {
                 if info.selected {
                     textlist
                         .set_selected_style(Style::default().fg(appearance.colors.text_special));
@@ -128,9 +162,160 @@ impl ChannelDisplay {
         }
     }
 
+    /// fetch a batch of videos, playlists or posts of the current page, with filters applied
+    fn fetch_batch(
+        framework: &FrameworkClean,
+        continuation: Option<&str>,
+    ) -> Result<(Vec<Item>, Option<String>), Box<dyn Error>> {
+        let page = framework.data.state.get::<Page>().unwrap();
+        let channel_page = page.channeldisplay();
+        let client = &framework.data.global.get::<InvidiousClient>().unwrap().0;
+        let image_index = framework
+            .data
+            .global
+            .get::<MainConfig>()
+            .unwrap()
+            .image_index;
+
+        let videos = |tab| {
+            channel_videos(
+                client,
+                &channel_page.id,
+                tab,
+                channel_page.sort,
+                continuation,
+                image_index,
+            )
+        };
+        let (mut items, continuation) = match channel_page.r#type {
+            ChannelDisplayPageType::Videos => videos(VideosTab::Videos)?,
+            ChannelDisplayPageType::Shorts => videos(VideosTab::Shorts)?,
+            ChannelDisplayPageType::Streams => videos(VideosTab::Streams)?,
+            ChannelDisplayPageType::Playlists => {
+                channel_playlists(client, &channel_page.id, channel_page.sort, continuation)?
+            }
+            ChannelDisplayPageType::Community => {
+                channel_community(client, &channel_page.id, continuation)?
+            }
+            ChannelDisplayPageType::Search => channel_search(
+                client,
+                &channel_page.id,
+                &channel_page.query,
+                continuation,
+                image_index,
+            )?,
+            ChannelDisplayPageType::Main => unreachable!("the main page does not have batches"),
+        };
+
+        let filters = framework.data.global.get::<FiltersConfig>().unwrap();
+        match channel_page.r#type {
+            // length filters would hide everything in these tabs
+            ChannelDisplayPageType::Shorts | ChannelDisplayPageType::Streams => FiltersConfig {
+                min_length: 0,
+                hide_shorts: false,
+                hide_live: false,
+                ..filters.clone()
+            }
+            .apply(&mut items),
+            _ => filters.apply(&mut items),
+        }
+        framework.data.global.get::<HideWatched>().unwrap().apply(
+            page,
+            framework.data.global.get::<WatchHistory>().unwrap(),
+            &mut items,
+        );
+        download_all_images(items.iter().map(|item| item.into()).collect());
+
+        Ok((items, continuation))
+    }
+
+    /// replaces the `Item::LoadMore` at the end of the list with the next batch of items
+    fn load_more(&mut self, framework: &mut FrameworkClean) -> Result<(), Box<dyn Error>> {
+        let (items, textlist, continuation) = match self {
+            Self::Videos {
+                videos: items,
+                textlist,
+                continuation,
+                ..
+            }
+            | Self::Playlists {
+                playlists: items,
+                textlist,
+                continuation,
+                ..
+            }
+            | Self::Community {
+                posts: items,
+                textlist,
+                continuation,
+                ..
+            } => (items, textlist, continuation),
+            _ => return Ok(()),
+        };
+
+        let (new_items, next) = Self::fetch_batch(framework, continuation.as_deref())?;
+        items.pop();
+        items.extend(new_items);
+        if next.is_some() {
+            items.push(Item::LoadMore);
+        }
+        *continuation = next;
+        textlist.set_items(items)?;
+
+        self.update();
+        framework
+            .data
+            .global
+            .get_mut::<Status>()
+            .unwrap()
+            .render_image = true;
+        set_envs(
+            self.infalte_item_update(
+                framework.data.global.get::<MainConfig>().unwrap(),
+                framework.data.global.get::<Status>().unwrap(),
+            )
+            .into_iter(),
+            &mut framework.data.state.get_mut::<StateEnvs>().unwrap().0,
+        );
+
+        Ok(())
+    }
+
     /// handles when select (enter) is pressed, generally loads the hovered item in a
     /// `SingleItemPage`
-    fn select_at_cursor(&self, framework: &mut FrameworkClean) {
+    fn select_at_cursor(&mut self, framework: &mut FrameworkClean) {
+        if let Self::Videos {
+            videos: items,
+            textlist,
+            ..
+        }
+        | Self::Playlists {
+            playlists: items,
+            textlist,
+            ..
+        }
+        | Self::Community {
+            posts: items,
+            textlist,
+            ..
+        } = self
+        {
+            if matches!(items.get(textlist.selected), Some(Item::LoadMore)) {
+                if let Err(e) = self.load_more(framework) {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Error(e.to_string());
+                }
+                framework
+                    .data
+                    .state
+                    .get_mut::<Tasks>()
+                    .unwrap()
+                    .priority
+                    .push(Task::RenderAll);
+                return;
+            }
+        }
+
         match self {
             Self::None | Self::Main { .. } => {}
             Self::Videos {
@@ -146,6 +331,7 @@ impl ChannelDisplay {
                         .priority
                         .push(Task::LoadPage(Page::SingleItem(SingleItemPage::Video(
                             videos[textlist.selected].minivideo().unwrap().id.clone(),
+                            None,
                         ))));
                 } else {
                     *framework.data.global.get_mut::<Message>().unwrap() =
@@ -176,6 +362,39 @@ impl ChannelDisplay {
                         Message::Error(String::from("There is nothing to select"));
                 }
             }
+            Self::Community {
+                posts, textlist, ..
+            } => {
+                // opens the attached video or playlist
+                let page = match posts.get(textlist.selected) {
+                    Some(Item::CommunityPost(CommunityPostItem {
+                        attachment: Some(PostAttachment::Video { id, .. }),
+                        ..
+                    })) => Page::SingleItem(SingleItemPage::Video(id.clone(), None)),
+                    Some(Item::CommunityPost(CommunityPostItem {
+                        attachment: Some(PostAttachment::Playlist { id, .. }),
+                        ..
+                    })) => Page::SingleItem(SingleItemPage::Playlist(id.clone())),
+                    Some(_) => {
+                        *framework.data.global.get_mut::<Message>().unwrap() = Message::Message(
+                            String::from("This post has no video or playlist attached"),
+                        );
+                        return;
+                    }
+                    None => {
+                        *framework.data.global.get_mut::<Message>().unwrap() =
+                            Message::Error(String::from("There is nothing to select"));
+                        return;
+                    }
+                };
+                framework
+                    .data
+                    .state
+                    .get_mut::<Tasks>()
+                    .unwrap()
+                    .priority
+                    .push(Task::LoadPage(page));
+            }
         }
     }
 
@@ -193,6 +412,12 @@ impl ChannelDisplay {
                 textlist,
                 iteminfo,
                 ..
+            }
+            | Self::Community {
+                posts: items,
+                textlist,
+                iteminfo,
+                ..
             } => {
                 if !items.is_empty()
                     && items[textlist.selected].id() != iteminfo.item.as_ref().unwrap().id()
@@ -265,6 +490,12 @@ impl FrameworkItem for ChannelDisplay {
                 iteminfo,
                 grid,
                 ..
+            }
+            | Self::Community {
+                textlist,
+                iteminfo,
+                grid,
+                ..
             } => {
                 let inner = &grid.chunks(area).unwrap()[0];
 
@@ -344,7 +575,13 @@ impl FrameworkItem for ChannelDisplay {
                 }
             }
             Self::Playlists {
-                playlists,
+                playlists: items,
+                textlist,
+                iteminfo,
+                ..
+            }
+            | Self::Community {
+                posts: items,
                 textlist,
                 iteminfo,
                 ..
@@ -361,7 +598,7 @@ impl FrameworkItem for ChannelDisplay {
                     _ => false,
                 };
 
-                if updated && !playlists.is_empty() {
+                if updated && !items.is_empty() {
                     framework
                         .data
                         .state
@@ -375,7 +612,15 @@ impl FrameworkItem for ChannelDisplay {
                         .get_mut::<Status>()
                         .unwrap()
                         .render_image = true;
-                    iteminfo.item = Some(playlists[textlist.selected].clone());
+                    iteminfo.item = Some(items[textlist.selected].clone());
+                    set_envs(
+                        self.infalte_item_update(
+                            framework.data.global.get::<MainConfig>().unwrap(),
+                            framework.data.global.get::<Status>().unwrap(),
+                        )
+                        .into_iter(),
+                        &mut framework.data.state.get_mut::<StateEnvs>().unwrap().0,
+                    );
                 }
             }
             _ => {}
@@ -411,67 +656,60 @@ impl FrameworkItem for ChannelDisplay {
                     channel,
                 }
             }
-            ChannelDisplayPageType::Videos => {
-                let videos = framework
-                    .data
-                    .global
-                    .get::<InvidiousClient>()
-                    .unwrap()
-                    .0
-                    .channel_videos(&page.id, None)?
-                    .videos
-                    .into_iter()
-                    .map(|video| Item::from_channel_video(video, mainconfig.image_index))
-                    .collect::<Vec<_>>();
-                download_all_images(videos.iter().map(|item| item.into()).collect());
-                *self = Self::Videos {
-                    textlist: TextList::default()
-                        .ascii_only(!mainconfig.allow_unicode)
-                        .border_type(appearance.borders)
-                        .style(Style::default().fg(appearance.colors.text))
-                        .items(&videos)?,
-                    iteminfo: ItemInfo::new(videos.first().cloned()),
-                    grid: Grid::new(
-                        vec![Constraint::Percentage(60), Constraint::Percentage(40)],
-                        vec![Constraint::Percentage(100)],
-                    )?
-                    .border_type(appearance.borders),
-                    videos,
-                };
-            }
-            ChannelDisplayPageType::Playlists => {
-                let playlists = framework
-                    .data
-                    .global
-                    .get::<InvidiousClient>()
-                    .unwrap()
-                    .0
-                    .channel_playlists(&page.id, None)?
-                    .playlists
-                    .into_iter()
-                    .map(Item::from_channel_playlist)
-                    .collect::<Vec<_>>();
-                download_all_images(playlists.iter().map(|item| item.into()).collect());
-                *self = Self::Playlists {
-                    textlist: TextList::default()
-                        .ascii_only(!mainconfig.allow_unicode)
-                        .border_type(appearance.borders)
-                        .style(Style::default().fg(appearance.colors.text))
-                        .items(&playlists)?,
-                    iteminfo: ItemInfo::new(playlists.first().cloned()),
-                    grid: Grid::new(
-                        vec![Constraint::Percentage(60), Constraint::Percentage(40)],
-                        vec![Constraint::Percentage(100)],
-                    )?
-                    .border_type(appearance.borders),
-                    playlists,
+            ChannelDisplayPageType::Videos
+            | ChannelDisplayPageType::Shorts
+            | ChannelDisplayPageType::Streams
+            | ChannelDisplayPageType::Playlists
+            | ChannelDisplayPageType::Community
+            | ChannelDisplayPageType::Search => {
+                let (mut items, continuation) = Self::fetch_batch(framework, None)?;
+                if continuation.is_some() {
+                    items.push(Item::LoadMore);
+                }
+
+                let textlist = TextList::default()
+                    .ascii_only(!mainconfig.allow_unicode)
+                    .border_type(appearance.borders)
+                    .style(Style::default().fg(appearance.colors.text))
+                    .items(&items)?;
+                let iteminfo = ItemInfo::new(items.first().cloned());
+                let grid = Grid::new(
+                    vec![Constraint::Percentage(60), Constraint::Percentage(40)],
+                    vec![Constraint::Percentage(100)],
+                )?
+                .border_type(appearance.borders);
+
+                *self = match page.r#type {
+                    ChannelDisplayPageType::Playlists => Self::Playlists {
+                        playlists: items,
+                        textlist,
+                        iteminfo,
+                        grid,
+                        continuation,
+                    },
+                    ChannelDisplayPageType::Community => Self::Community {
+                        posts: items,
+                        textlist,
+                        iteminfo,
+                        grid,
+                        continuation,
+                    },
+                    _ => Self::Videos {
+                        videos: items,
+                        textlist,
+                        iteminfo,
+                        grid,
+                        continuation,
+                    },
                 };
             }
         }
 
+        let channel_id = page.id.clone();
         set_envs(
             self.infalte_item_update(mainconfig, framework.data.global.get::<Status>().unwrap())
-                .into_iter(),
+                .into_iter()
+                .chain([(String::from("channel-id"), channel_id)]),
             &mut framework.data.state.get_mut::<StateEnvs>().unwrap().0,
         );
 
@@ -488,7 +726,9 @@ impl FrameworkItem for ChannelDisplay {
     ) -> bool {
         match self {
             Self::None | Self::Main { .. } => return false,
-            Self::Videos { textlist, grid, .. } | Self::Playlists { textlist, grid, .. } => {
+            Self::Videos { textlist, grid, .. }
+            | Self::Playlists { textlist, grid, .. }
+            | Self::Community { textlist, grid, .. } => {
                 let chunk = grid
                     .chunks(
                         if let Some(prev_frame) =
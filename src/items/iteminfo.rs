@@ -7,7 +7,10 @@ use tui_additions::framework::FrameworkItem;
 #[cfg(any(feature = "sixel", feature = "halfblock"))]
 use viuer::{print_from_file, Config};
 
-use crate::{config::*, global::structs::*};
+use crate::{
+    config::*,
+    global::{functions::cache_dir, structs::*},
+};
 
 /// an item info displays info of any `Item`s
 #[derive(Clone)]
@@ -53,10 +56,7 @@ impl FrameworkItem for ItemInfo {
 
             #[cfg(any(feature = "sixel", feature = "halfblock"))]
             {
-                let thumbnail_path = home::home_dir()
-                    .unwrap()
-                    .join(".cache/youtube-tui/thumbnails/")
-                    .join(item.thumbnail_id());
+                let thumbnail_path = cache_dir().join("thumbnails").join(item.thumbnail_id());
                 if thumbnail_path.exists() {
                     #[cfg(any(feature = "sixel", feature = "halfblock"))]
                     let config = Config {
@@ -103,7 +103,7 @@ impl FrameworkItem for ItemInfo {
 
         // Each "span" contains a string and a Style, and they are one line max each
         // A "text" is used for descriptions in video/playlist and channels, and starts a new line if the old one runs out
-        let (spans, text) = match item {
+        let (mut spans, text) = match item {
             Item::MiniVideo(minivideo) => {
                 let mut out = (
                     vec![
@@ -318,6 +318,61 @@ impl FrameworkItem for ItemInfo {
                     Style::default().fg(appearance.colors.item_info.description),
                 )),
             ),
+            Item::CommunityPost(post) => {
+                let mut spans = vec![
+                    (
+                        String::from("[Community post]"),
+                        Style::default().fg(appearance.colors.item_info.tag),
+                    ),
+                    (
+                        format!("Posted by {}", post.channel),
+                        Style::default().fg(appearance.colors.item_info.author),
+                    ),
+                    (
+                        format!("{} likes", post.likes),
+                        Style::default().fg(appearance.colors.item_info.likes),
+                    ),
+                    (
+                        format!("Published {}", post.published),
+                        Style::default().fg(appearance.colors.item_info.published),
+                    ),
+                ];
+                let mut content = post.content.clone();
+
+                // images are displayed as the thumbnail, polls are displayed after the text
+                match &post.attachment {
+                    Some(PostAttachment::Image(_)) => spans.push((
+                        String::from("Attached image"),
+                        Style::default().fg(appearance.colors.item_info.tag),
+                    )),
+                    Some(PostAttachment::Video { title, .. }) => spans.push((
+                        format!("Attached video: {title}"),
+                        Style::default().fg(appearance.colors.item_info.title),
+                    )),
+                    Some(PostAttachment::Playlist { title, .. }) => spans.push((
+                        format!("Attached playlist: {title}"),
+                        Style::default().fg(appearance.colors.item_info.title),
+                    )),
+                    Some(PostAttachment::Poll {
+                        choices,
+                        total_votes,
+                    }) => {
+                        content.push_str(&format!("\n\nPoll ({total_votes} votes):"));
+                        choices
+                            .iter()
+                            .for_each(|choice| content.push_str(&format!("\n- {choice}")));
+                    }
+                    None => {}
+                }
+
+                (
+                    spans,
+                    Some((
+                        content,
+                        Style::default().fg(appearance.colors.item_info.description),
+                    )),
+                )
+            }
             Item::Unknown(searchitem_transitional) => (
                 vec![(
                     format!("Unknown type `{}`", searchitem_transitional.r#type),
@@ -335,8 +390,152 @@ impl FrameworkItem for ItemInfo {
                 )],
                 None,
             ),
+            Item::LoadMore => (
+                vec![(
+                    String::from("Load more"),
+                    Style::default().fg(appearance.colors.item_info.page_turner),
+                )],
+                None,
+            ),
+            Item::SearchQuery(search) => (
+                vec![
+                    (
+                        String::from("[Search]"),
+                        Style::default().fg(appearance.colors.item_info.tag),
+                    ),
+                    (
+                        search.query.clone(),
+                        Style::default().fg(appearance.colors.item_info.title),
+                    ),
+                    (
+                        format!("Sort by: {}", search.filters.sort.to_string()),
+                        Style::default().fg(appearance.colors.item_info.description),
+                    ),
+                    (
+                        format!("Upload date: {}", search.filters.date.to_string()),
+                        Style::default().fg(appearance.colors.item_info.description),
+                    ),
+                    (
+                        format!("Duration: {}", search.filters.duration.to_string()),
+                        Style::default().fg(appearance.colors.item_info.description),
+                    ),
+                    (
+                        format!("Type: {}", search.filters.r#type.to_string()),
+                        Style::default().fg(appearance.colors.item_info.description),
+                    ),
+                    (
+                        format!("Features: {}", search.filters.features),
+                        Style::default().fg(appearance.colors.item_info.description),
+                    ),
+                    (
+                        format!("Region: {}", search.filters.region),
+                        Style::default().fg(appearance.colors.item_info.description),
+                    ),
+                    (
+                        format!("Language: {}", search.filters.language),
+                        Style::default().fg(appearance.colors.item_info.description),
+                    ),
+                ],
+                None,
+            ),
+            Item::SavedSearch(name) => {
+                let mut out = vec![
+                    (
+                        String::from("[Saved search]"),
+                        Style::default().fg(appearance.colors.item_info.tag),
+                    ),
+                    (
+                        name.clone(),
+                        Style::default().fg(appearance.colors.item_info.title),
+                    ),
+                ];
+                if let Some(saved) = framework
+                    .data
+                    .global
+                    .get::<SavedSearches>()
+                    .unwrap()
+                    .get(name)
+                {
+                    let search = &saved.search;
+                    out.extend([
+                        (
+                            format!("Query: {}", search.query),
+                            Style::default().fg(appearance.colors.item_info.description),
+                        ),
+                        (
+                            format!("Sort by: {}", search.filters.sort.to_string()),
+                            Style::default().fg(appearance.colors.item_info.description),
+                        ),
+                        (
+                            format!("Upload date: {}", search.filters.date.to_string()),
+                            Style::default().fg(appearance.colors.item_info.description),
+                        ),
+                        (
+                            format!("Duration: {}", search.filters.duration.to_string()),
+                            Style::default().fg(appearance.colors.item_info.description),
+                        ),
+                        (
+                            format!("Type: {}", search.filters.r#type.to_string()),
+                            Style::default().fg(appearance.colors.item_info.description),
+                        ),
+                        (
+                            format!("Features: {}", search.filters.features),
+                            Style::default().fg(appearance.colors.item_info.description),
+                        ),
+                        (
+                            format!("Region: {}", search.filters.region),
+                            Style::default().fg(appearance.colors.item_info.description),
+                        ),
+                        (
+                            format!("Language: {}", search.filters.language),
+                            Style::default().fg(appearance.colors.item_info.description),
+                        ),
+                    ]);
+                    if !saved.unread.is_empty() {
+                        out.insert(
+                            1,
+                            (
+                                format!("[{} new]", saved.unread.len()),
+                                Style::default().fg(appearance.colors.item_info.unread),
+                            ),
+                        );
+                    }
+                }
+                (out, None)
+            }
         };
 
+        if item
+            .id()
+            .map(|id| {
+                framework
+                    .data
+                    .global
+                    .get::<LastSeen>()
+                    .unwrap()
+                    .is_unread(id)
+                    || match framework.data.state.get::<Page>().unwrap() {
+                        Page::MainMenu(MainMenuPage::SavedSearch(name)) => framework
+                            .data
+                            .global
+                            .get::<SavedSearches>()
+                            .unwrap()
+                            .get(name)
+                            .is_some_and(|saved| saved.is_unread(id)),
+                        _ => false,
+                    }
+            })
+            .unwrap_or_default()
+        {
+            spans.insert(
+                1,
+                (
+                    String::from("[New since last visit]"),
+                    Style::default().fg(appearance.colors.item_info.unread),
+                ),
+            );
+        }
+
         let mut y = if scroll >= area.height { 0 } else { scroll } + area.y;
         let bottom = area.bottom();
 
@@ -363,9 +562,14 @@ impl FrameworkItem for ItemInfo {
         if text.is_empty() {
             return;
         }
-        let paragraph = Paragraph::new(format!("Description:\n{text}"))
-            .style(style)
-            .wrap(Wrap { trim: true });
+        // the text of a post is the post itself, so it is not labelled as a description
+        let paragraph = Paragraph::new(if let Item::CommunityPost(_) = item {
+            text
+        } else {
+            format!("Description:\n{text}")
+        })
+        .style(style)
+        .wrap(Wrap { trim: true });
         frame.render_widget(
             paragraph,
             Rect {
@@ -1,17 +1,17 @@
-use std::error::Error;
+use std::{collections::HashSet, error::Error};
 
 use crate::{
     config::*,
-    global::{functions::*, structs::*},
+    global::{functions::*, structs::*, traits::DataFile},
     items::ItemInfo,
 };
-use home::home_dir;
 use tui::{
     layout::{Constraint, Rect},
     style::Style,
+    widgets::Block,
 };
 use tui_additions::{
-    framework::{FrameworkClean, FrameworkItem},
+    framework::{FrameworkClean, FrameworkData, FrameworkItem},
     widgets::{Grid, TextList},
 };
 
@@ -23,6 +23,10 @@ pub struct ItemList {
     pub items: Vec<Item>,
     pub textlist: TextList,
     pub grid: Grid,
+    /// the revision the labels of the text list were built with
+    labels_revision: Option<LabelsRevision>,
+    /// indexes of watched items, which are coloured with `colors.watched`
+    watched: HashSet<usize>,
 }
 
 impl ItemList {
@@ -35,7 +39,8 @@ impl ItemList {
             return Vec::new();
         }
 
-        match &self.items[self.textlist.selected] {
+        let hovered = &self.items[self.textlist.selected];
+        let mut envs = match hovered {
             Item::MiniVideo(MiniVideoItem { id, .. })
             | Item::FullVideo(FullVideoItem { id, .. }) => {
                 vec![(
@@ -75,10 +80,114 @@ impl ItemList {
                     ),
                 )]
             }
-            Item::Page(_) | Item::Unknown(_) => {
+            Item::SearchQuery(search) => vec![(
+                String::from("hover-url"),
+                match status.provider {
+                    Provider::YouTube => {
+                        format!("https://youtube.com/results?{}", search.to_string())
+                    }
+                    Provider::Invidious => format!(
+                        "{}/search?{}",
+                        mainconfig.invidious_instance,
+                        search.to_string()
+                    ),
+                },
+            )],
+            Item::CommunityPost(_)
+            | Item::SavedSearch(_)
+            | Item::Page(_)
+            | Item::LoadMore
+            | Item::Unknown(_) => {
                 vec![(String::from("hover-url"), String::from("not avaliable"))]
             }
-        }
+        };
+
+        envs.push((
+            String::from("hover-id"),
+            hovered.id().unwrap_or("not avaliable").to_string(),
+        ));
+        envs.push((
+            String::from("hover-channel-id"),
+            match hovered {
+                Item::MiniVideo(MiniVideoItem { channel_id, .. })
+                | Item::FullVideo(FullVideoItem { channel_id, .. })
+                | Item::MiniPlaylist(MiniPlaylistItem { channel_id, .. })
+                | Item::FullPlaylist(FullPlaylistItem { channel_id, .. })
+                | Item::CommunityPost(CommunityPostItem { channel_id, .. }) => channel_id,
+                Item::MiniChannel(MiniChannelItem { id, .. })
+                | Item::FullChannel(FullChannelItem { id, .. }) => id,
+                Item::SearchQuery(_)
+                | Item::SavedSearch(_)
+                | Item::Page(_)
+                | Item::LoadMore
+                | Item::Unknown(_) => "not avaliable",
+            }
+            .to_string(),
+        ));
+        envs
+    }
+
+    /// updates the text displayed in each row, unread videos are marked with `[new]` and watched
+    /// items are marked with `watched_marker`
+    pub fn update_labels(&mut self, data: &FrameworkData) {
+        self.labels_revision = Some(*data.global.get::<LabelsRevision>().unwrap());
+        let last_seen = data.global.get::<LastSeen>().unwrap();
+        let saved_searches = data.global.get::<SavedSearches>().unwrap();
+        // unread results of the saved search feed currently displayed
+        let saved_search = match data.state.get::<Page>().unwrap() {
+            Page::MainMenu(MainMenuPage::SavedSearch(name)) => saved_searches.get(name),
+            _ => None,
+        };
+        let watch_history = data.global.get::<WatchHistory>().unwrap();
+        let marker = &data
+            .global
+            .get::<AppearanceConfig>()
+            .unwrap()
+            .watched_marker;
+        // everything in the history page is watched, no point marking them
+        let mark_watched =
+            data.state.get::<Page>().unwrap() != &Page::MainMenu(MainMenuPage::History);
+
+        let watched = if mark_watched {
+            watch_history.0.iter().filter_map(Item::id).collect()
+        } else {
+            HashSet::new()
+        };
+        self.watched = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.id().is_some_and(|id| watched.contains(id)))
+            .map(|(index, _)| index)
+            .collect();
+        let labels = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let label = match (item, item.id()) {
+                    (_, Some(id))
+                        if last_seen.is_unread(id)
+                            || saved_search.is_some_and(|saved| saved.is_unread(id)) =>
+                    {
+                        format!("[new] {item}")
+                    }
+                    (Item::SavedSearch(name), _) => match saved_searches.get(name) {
+                        Some(saved) if !saved.unread.is_empty() => {
+                            format!("[{} new] {item}", saved.unread.len())
+                        }
+                        _ => item.to_string(),
+                    },
+                    _ => item.to_string(),
+                };
+                if self.watched.contains(&index) {
+                    format!("{marker}{label}")
+                } else {
+                    label
+                }
+            })
+            .collect::<Vec<_>>();
+        let _ = self.textlist.set_items(&labels);
     }
 
     fn update_appearance(
@@ -117,10 +226,9 @@ impl ItemList {
 
     /// handles select (enter)
     fn select_at_cursor(&self, framework: &mut FrameworkClean) {
-        let page_to_load = if home_dir()
-            .unwrap()
+        let page_to_load = if cache_dir()
             .join(format!(
-                ".cache/youtube-tui/info/{}.json",
+                "info/{}.json",
                 self.items[self.textlist.selected].id().unwrap_or_default()
             ))
             .exists()
@@ -128,7 +236,7 @@ impl ItemList {
             match &self.items[self.textlist.selected] {
                 Item::MiniVideo(MiniVideoItem { id, .. })
                 | Item::FullVideo(FullVideoItem { id, .. }) => {
-                    Some(Page::SingleItem(SingleItemPage::Video(id.clone())))
+                    Some(Page::SingleItem(SingleItemPage::Video(id.clone(), None)))
                 }
                 Item::MiniPlaylist(MiniPlaylistItem { id, .. })
                 | Item::FullPlaylist(FullPlaylistItem { id, .. }) => {
@@ -148,6 +256,14 @@ impl ItemList {
                         .push(Task::RenderAll);
                     None
                 }
+                // the search is loaded with the same filters as when it was searched
+                Item::SearchQuery(search) => {
+                    *framework.data.state.get_mut::<Search>().unwrap() = search.clone();
+                    Some(Page::Search(search.clone()))
+                }
+                Item::SavedSearch(name) => {
+                    Some(Page::MainMenu(MainMenuPage::SavedSearch(name.clone())))
+                }
                 Item::Page(b) => match framework.data.state.get::<Page>().unwrap() {
                     Page::Search(search) => Some(Page::Search(Search {
                         page: if *b { search.page + 1 } else { search.page - 1 },
@@ -155,12 +271,15 @@ impl ItemList {
                     })),
                     _ => unreachable!("Page turners can only be used in search pages"),
                 },
+                Item::LoadMore | Item::CommunityPost(_) => {
+                    unreachable!("Load more and community posts can only be used in channel pages")
+                }
             }
         } else {
             match &self.items[self.textlist.selected] {
                 Item::MiniVideo(MiniVideoItem { id, .. })
                 | Item::FullVideo(FullVideoItem { id, .. }) => {
-                    Some(Page::SingleItem(SingleItemPage::Video(id.clone())))
+                    Some(Page::SingleItem(SingleItemPage::Video(id.clone(), None)))
                 }
                 Item::MiniPlaylist(MiniPlaylistItem { id, .. })
                 | Item::FullPlaylist(FullPlaylistItem { id, .. }) => {
@@ -171,6 +290,8 @@ impl ItemList {
                     Some(Page::ChannelDisplay(ChannelDisplayPage {
                         id: id.clone(),
                         r#type: ChannelDisplayPageType::Main,
+                        sort: ChannelSort::default(),
+                        query: String::new(),
                     }))
                 }
                 Item::Unknown(_) => {
@@ -185,6 +306,14 @@ impl ItemList {
                         .push(Task::RenderAll);
                     None
                 }
+                // the search is loaded with the same filters as when it was searched
+                Item::SearchQuery(search) => {
+                    *framework.data.state.get_mut::<Search>().unwrap() = search.clone();
+                    Some(Page::Search(search.clone()))
+                }
+                Item::SavedSearch(name) => {
+                    Some(Page::MainMenu(MainMenuPage::SavedSearch(name.clone())))
+                }
                 Item::Page(b) => match framework.data.state.get::<Page>().unwrap() {
                     Page::Search(search) => Some(Page::Search(Search {
                         page: if *b { search.page + 1 } else { search.page - 1 },
@@ -192,6 +321,9 @@ impl ItemList {
                     })),
                     _ => unreachable!("Page turners can only be used in search pages"),
                 },
+                Item::LoadMore | Item::CommunityPost(_) => {
+                    unreachable!("Load more and community posts can only be used in channel pages")
+                }
             }
         };
 
@@ -218,6 +350,8 @@ impl Default for ItemList {
                 vec![Constraint::Percentage(100)],
             )
             .unwrap(),
+            labels_revision: None,
+            watched: HashSet::new(),
         }
     }
 }
@@ -247,6 +381,10 @@ impl FrameworkItem for ItemList {
         }
 
         self.update_appearance(appearance, mainconfig, &info);
+        // watched and unread marks may have changed since the labels were built
+        if self.labels_revision.as_ref() != framework.data.global.get::<LabelsRevision>() {
+            self.update_labels(framework.data);
+        }
 
         // creates the grid
         let grid = self.grid.clone();
@@ -266,6 +404,26 @@ impl FrameworkItem for ItemList {
         frame.render_widget(grid, area);
         frame.render_widget(textlist, chunks[0]);
 
+        // the text list renders all rows in the same style, so watched rows are coloured after,
+        // the row of the cursor takes 3 lines and keeps its own style
+        let mut y = chunks[0].y;
+        for index in (self.textlist.scroll..self.textlist.items.len())
+            .take(chunks[0].height.saturating_sub(2) as usize)
+        {
+            if index == self.textlist.selected {
+                y += 3;
+                continue;
+            }
+            if self.watched.contains(&index) {
+                let row = Rect::new(chunks[0].x + 1, y, chunks[0].width.saturating_sub(2), 1);
+                frame.render_widget(
+                    Block::default().style(Style::default().fg(appearance.colors.watched)),
+                    row.intersection(chunks[0]),
+                );
+            }
+            y += 1;
+        }
+
         // used the `.render()` function in self.info because it is an ItemInfo and impls FrameworkItem instead of Widget
         self.info
             .render(frame, framework, chunks[1], popup_render, info);
@@ -293,9 +451,10 @@ impl FrameworkItem for ItemList {
 
         // fetch the items using the invidious api
         match page {
-            Page::MainMenu(MainMenuPage::Trending) => {
+            Page::MainMenu(MainMenuPage::Trending(tab)) => {
+                let region = &framework.data.global.get::<MainConfig>().unwrap().region;
                 self.items = client
-                    .trending(None)?
+                    .trending(Some(&tab.params(region)))?
                     .videos
                     .into_iter()
                     .map(|item| Item::from_trending_video(item, image_index))
@@ -309,7 +468,42 @@ impl FrameworkItem for ItemList {
                     .map(|item| Item::from_popular_item(item, image_index))
                     .collect();
             }
-            Page::MainMenu(MainMenuPage::Subscriptions) => {}
+            Page::MainMenu(MainMenuPage::Subscriptions) => {
+                let mainconfig = framework.data.global.get::<MainConfig>().unwrap();
+                let channel_ids = framework
+                    .data
+                    .global
+                    .get::<Subscriptions>()
+                    .unwrap()
+                    .0
+                    .iter()
+                    .filter_map(|channel| channel.id().map(String::from))
+                    .collect::<Vec<_>>();
+                let mut entries = subscriptions_feed(
+                    &channel_ids,
+                    client,
+                    match framework.data.global.get::<Status>().unwrap().provider {
+                        Provider::YouTube => "https://www.youtube.com",
+                        Provider::Invidious => &mainconfig.invidious_instance,
+                    },
+                    mainconfig.use_channel_feeds,
+                    image_index,
+                )?;
+                // filtered before updating so hidden videos are not counted as unread
+                let filters = framework.data.global.get::<FiltersConfig>().unwrap();
+                entries.retain(|entry| filters.allows(&entry.item));
+
+                let last_seen = framework.data.global.get_mut::<LastSeen>().unwrap();
+                last_seen.update(&entries);
+                let _ = last_seen.save();
+                framework
+                    .data
+                    .global
+                    .get_mut::<LabelsRevision>()
+                    .unwrap()
+                    .bump();
+                self.items = entries.into_iter().map(|entry| entry.item).collect();
+            }
             Page::MainMenu(MainMenuPage::Library) => {
                 let history = framework.data.global.get::<Library>().unwrap();
                 self.items = history.0.clone().into_iter().rev().collect();
@@ -320,6 +514,49 @@ impl FrameworkItem for ItemList {
                 let history = framework.data.global.get::<WatchHistory>().unwrap();
                 self.items = history.0.clone().into_iter().rev().collect();
             }
+            // saved searches are listed above past searches
+            Page::MainMenu(MainMenuPage::SearchHistory) => {
+                let saved_searches = framework.data.global.get::<SavedSearches>().unwrap();
+                let history = framework.data.global.get::<SearchHistory>().unwrap();
+                self.items = saved_searches
+                    .0
+                    .iter()
+                    .map(|saved| Item::SavedSearch(saved.name.clone()))
+                    .chain(history.0.iter().rev().cloned().map(Item::SearchQuery))
+                    .collect();
+            }
+            Page::MainMenu(MainMenuPage::SavedSearch(name)) => {
+                let search = match framework
+                    .data
+                    .global
+                    .get::<SavedSearches>()
+                    .unwrap()
+                    .get(name)
+                {
+                    Some(saved) => saved.search.to_string(),
+                    None => return Err(format!("No saved search named `{name}`").into()),
+                };
+                self.items = client
+                    .search(Some(&search))?
+                    .items
+                    .into_iter()
+                    .map(|item| Item::from_search_item(item, image_index))
+                    .collect();
+                // filtered before updating so hidden results are not counted as unread
+                let filters = framework.data.global.get::<FiltersConfig>().unwrap();
+                self.items.retain(|item| filters.allows(item));
+
+                let ids = self.items.iter().filter_map(Item::id).collect::<Vec<_>>();
+                let saved_searches = framework.data.global.get_mut::<SavedSearches>().unwrap();
+                saved_searches.get_mut(name).unwrap().update(&ids);
+                let _ = saved_searches.save();
+                framework
+                    .data
+                    .global
+                    .get_mut::<LabelsRevision>()
+                    .unwrap()
+                    .bump();
+            }
             Page::Search(search) => {
                 self.items = client
                     .search(Some(&search.to_string()))?
@@ -337,6 +574,30 @@ impl FrameworkItem for ItemList {
             _ => unreachable!("item `ItemList` cannot be used in `{page:?}`"),
         }
 
+        // only successful searches are remembered
+        if let Page::Search(search) = framework.data.state.get::<Page>().unwrap() {
+            let max_search_history = framework
+                .data
+                .global
+                .get::<MainConfig>()
+                .unwrap()
+                .max_search_history;
+            let search_history = framework.data.global.get_mut::<SearchHistory>().unwrap();
+            search_history.push(search, max_search_history);
+            let _ = search_history.save();
+        }
+
+        framework
+            .data
+            .global
+            .get::<FiltersConfig>()
+            .unwrap()
+            .apply(&mut self.items);
+        framework.data.global.get::<HideWatched>().unwrap().apply(
+            framework.data.state.get::<Page>().unwrap(),
+            framework.data.global.get::<WatchHistory>().unwrap(),
+            &mut self.items,
+        );
         let mainconfig = framework.data.global.get::<MainConfig>().unwrap();
         let status = framework.data.global.get::<Status>().unwrap();
 
@@ -346,7 +607,7 @@ impl FrameworkItem for ItemList {
         }
 
         // update the items in text list
-        self.textlist.set_items(&self.items).unwrap();
+        self.update_labels(framework.data);
         self.update();
 
         set_envs(
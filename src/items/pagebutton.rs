@@ -1,7 +1,11 @@
-use crate::{config::AppearanceConfig, global::structs::*};
+use crate::{
+    config::{AppearanceConfig, MainConfig},
+    global::structs::*,
+};
 use tui::{
     layout::Alignment,
     style::Style,
+    text::Span,
     widgets::{Block, Borders, Paragraph},
 };
 use tui_additions::framework::FrameworkItem;
@@ -11,10 +15,19 @@ use tui_additions::framework::FrameworkItem;
 pub enum PageButton {
     Trending,
     Popular,
+    TrendingNow,
+    TrendingMusic,
+    TrendingGaming,
+    TrendingNews,
+    TrendingMovies,
     ChannelMain,
     ChannelVideos,
+    ChannelShorts,
+    ChannelStreams,
     ChannelPlaylists,
+    ChannelCommunity,
     History,
+    SearchHistory,
     Subscriptions,
     Library,
 }
@@ -22,21 +35,45 @@ pub enum PageButton {
 impl PageButton {
     pub fn page(&self, current_page: &Page) -> Page {
         match self {
-            Self::Trending => Page::MainMenu(MainMenuPage::Trending),
+            // stays on the current tab if already on a trending page
+            Self::Trending => match current_page {
+                Page::MainMenu(MainMenuPage::Trending(tab)) => {
+                    Page::MainMenu(MainMenuPage::Trending(*tab))
+                }
+                _ => Page::MainMenu(MainMenuPage::Trending(TrendingTab::default())),
+            },
+            Self::TrendingNow => Page::MainMenu(MainMenuPage::Trending(TrendingTab::Now)),
+            Self::TrendingMusic => Page::MainMenu(MainMenuPage::Trending(TrendingTab::Music)),
+            Self::TrendingGaming => Page::MainMenu(MainMenuPage::Trending(TrendingTab::Gaming)),
+            Self::TrendingNews => Page::MainMenu(MainMenuPage::Trending(TrendingTab::News)),
+            Self::TrendingMovies => Page::MainMenu(MainMenuPage::Trending(TrendingTab::Movies)),
             Self::Popular => Page::MainMenu(MainMenuPage::Popular),
             Self::ChannelMain => Page::ChannelDisplay(ChannelDisplayPage {
-                id: current_page.channeldisplay().id.clone(),
                 r#type: ChannelDisplayPageType::Main,
+                ..current_page.channeldisplay().clone()
             }),
             Self::ChannelVideos => Page::ChannelDisplay(ChannelDisplayPage {
-                id: current_page.channeldisplay().id.clone(),
                 r#type: ChannelDisplayPageType::Videos,
+                ..current_page.channeldisplay().clone()
+            }),
+            Self::ChannelShorts => Page::ChannelDisplay(ChannelDisplayPage {
+                r#type: ChannelDisplayPageType::Shorts,
+                ..current_page.channeldisplay().clone()
+            }),
+            Self::ChannelStreams => Page::ChannelDisplay(ChannelDisplayPage {
+                r#type: ChannelDisplayPageType::Streams,
+                ..current_page.channeldisplay().clone()
             }),
             Self::ChannelPlaylists => Page::ChannelDisplay(ChannelDisplayPage {
-                id: current_page.channeldisplay().id.clone(),
                 r#type: ChannelDisplayPageType::Playlists,
+                ..current_page.channeldisplay().clone()
+            }),
+            Self::ChannelCommunity => Page::ChannelDisplay(ChannelDisplayPage {
+                r#type: ChannelDisplayPageType::Community,
+                ..current_page.channeldisplay().clone()
             }),
             Self::History => Page::MainMenu(MainMenuPage::History),
+            Self::SearchHistory => Page::MainMenu(MainMenuPage::SearchHistory),
             Self::Subscriptions => Page::MainMenu(MainMenuPage::Subscriptions),
             Self::Library => Page::MainMenu(MainMenuPage::Library),
         }
@@ -46,7 +83,7 @@ impl PageButton {
         if *self == Self::Popular || *self == Self::Trending {
             match page {
                 Page::MainMenu(MainMenuPage::Popular) => *self = Self::Popular,
-                Page::MainMenu(MainMenuPage::Trending) => *self = Self::Trending,
+                Page::MainMenu(MainMenuPage::Trending(_)) => *self = Self::Trending,
                 _ => {}
             }
         } else if *self == Self::Subscriptions || *self == Self::Library {
@@ -64,10 +101,19 @@ impl ToString for PageButton {
         match self {
             Self::Popular => String::from("Popular"),
             Self::Trending => String::from("Trending"),
+            Self::TrendingNow => String::from("Now"),
+            Self::TrendingMusic => String::from("Music"),
+            Self::TrendingGaming => String::from("Gaming"),
+            Self::TrendingNews => String::from("News"),
+            Self::TrendingMovies => String::from("Movies"),
             Self::ChannelMain => String::from("Main"),
             Self::ChannelVideos => String::from("Videos"),
+            Self::ChannelShorts => String::from("Shorts"),
+            Self::ChannelStreams => String::from("Streams"),
             Self::ChannelPlaylists => String::from("Playlists"),
+            Self::ChannelCommunity => String::from("Community"),
             Self::History => String::from("History"),
+            Self::SearchHistory => String::from("Searches"),
             Self::Subscriptions => String::from("Subscriptions"),
             Self::Library => String::from("Library"),
         }
@@ -100,7 +146,7 @@ impl FrameworkItem for PageButton {
         let same_page = &self.page(framework.data.state.get::<Page>().unwrap())
             == framework.data.state.get::<Page>().unwrap();
 
-        let block = Block::default()
+        let mut block = Block::default()
             .border_type(appearance.borders)
             .border_style(Style::default().fg(if info.hover {
                 appearance.colors.outline_hover
@@ -110,6 +156,51 @@ impl FrameworkItem for PageButton {
                 appearance.colors.outline
             }))
             .borders(Borders::ALL);
+
+        // displays the number of unread videos on the top right corner, the searches button
+        // counts new results of saved searches
+        let unread = match self {
+            Self::Subscriptions => framework.data.global.get::<LastSeen>().unwrap().count(),
+            Self::SearchHistory => framework
+                .data
+                .global
+                .get::<SavedSearches>()
+                .unwrap()
+                .count(),
+            _ => 0,
+        };
+        if unread != 0 {
+            block = block
+                .title(Span::styled(
+                    format!("{unread} new"),
+                    Style::default().fg(appearance.colors.item_info.unread),
+                ))
+                .title_alignment(Alignment::Right);
+        }
+
+        // displays the region of trending videos on the trending button and the current tab
+        let trending_tab = matches!(
+            self,
+            Self::TrendingNow
+                | Self::TrendingMusic
+                | Self::TrendingGaming
+                | Self::TrendingNews
+                | Self::TrendingMovies
+        );
+        if *self == Self::Trending || trending_tab && same_page {
+            block = block
+                .title(Span::raw(
+                    framework
+                        .data
+                        .global
+                        .get::<MainConfig>()
+                        .unwrap()
+                        .region
+                        .as_str(),
+                ))
+                .title_alignment(Alignment::Right);
+        }
+
         let paragraph = Paragraph::new(self.to_string())
             .block(block)
             .alignment(Alignment::Center);
@@ -1,22 +1,313 @@
-use std::error::Error;
-
-use crate::{config::*, global::structs::*};
+use std::{
+    error::Error,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+};
 
-#[cfg(feature = "clipboard")]
-use crate::global::functions::*;
+use crate::{
+    config::*,
+    global::{functions::*, structs::*},
+};
 
 use crossterm::event::KeyCode;
 use tui::{
-    layout::Alignment,
+    layout::{Alignment, Rect},
     style::Style,
-    widgets::{Block, Borders},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+use tui_additions::{
+    framework::{FrameworkClean, FrameworkItem},
+    widgets::TextField,
 };
-use tui_additions::{framework::FrameworkItem, widgets::TextField};
 
 /// the search bar item
 #[derive(Clone, Default)]
 pub struct SearchBar {
     pub text_field: TextField,
+    /// searches within the current channel instead of the whole of YouTube
+    pub channel: bool,
+    /// suggestions for the current content, displayed in a dropdown when typing
+    pub suggestions: Vec<String>,
+    /// index of the hovered suggestion
+    pub suggestion_hover: Option<usize>,
+    /// incremented on every edit, so that suggestions for outdated input are discarded
+    generation: Arc<AtomicUsize>,
+    /// suggestions fetched in the background
+    fetched: SuggestionsSlot,
+    /// sends queries to the suggestions worker, started when suggestions are first needed
+    requests: Option<mpsc::Sender<SuggestionsRequest>>,
+    /// if suggestions are being fetched
+    waiting: bool,
+    /// if the dropdown is opened
+    opened: bool,
+    /// area of the dropdown when it was last rendered
+    dropdown: Option<Rect>,
+    /// index of the past search recalled with up and down, in `SearchHistory::recent()`
+    recall: Option<usize>,
+    /// content of the search bar before recalling past searches
+    draft: String,
+}
+
+impl SearchBar {
+    /// search bar for searching within the current channel
+    pub fn channel() -> Self {
+        Self {
+            channel: true,
+            ..Default::default()
+        }
+    }
+
+    /// fetches suggestions for the new content in the background
+    fn content_changed(&mut self, framework: &mut FrameworkClean) {
+        self.recall = None;
+
+        if self.channel
+            || !framework
+                .data
+                .global
+                .get::<MainConfig>()
+                .unwrap()
+                .search_suggestions
+        {
+            return;
+        }
+
+        let current = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        self.suggestion_hover = None;
+
+        if self.text_field.content.is_empty() {
+            self.suggestions.clear();
+            self.waiting = false;
+            return;
+        }
+
+        let request = SuggestionsRequest {
+            server: framework
+                .data
+                .global
+                .get::<InvidiousClient>()
+                .unwrap()
+                .0
+                .server
+                .clone(),
+            query: self.text_field.content.clone(),
+            generation: current,
+        };
+        let requests = self.requests.get_or_insert_with(|| {
+            suggestions_worker(self.generation.clone(), self.fetched.clone())
+        });
+        if requests.send(request).is_err() {
+            // the worker has stopped, another one is started on the next edit
+            self.requests = None;
+            return;
+        }
+        self.waiting = true;
+        framework
+            .data
+            .global
+            .get_mut::<Status>()
+            .unwrap()
+            .background_loading = true;
+    }
+
+    /// takes the suggestions fetched in the background (if any)
+    fn receive_suggestions(&mut self, framework: &mut FrameworkClean) {
+        if !self.waiting {
+            return;
+        }
+
+        match self.fetched.lock().unwrap().take() {
+            Some(suggestions) => {
+                self.suggestions = suggestions;
+                self.suggestion_hover = None;
+                self.waiting = false;
+            }
+            // keep re-rendering until the suggestions arrive
+            None => {
+                framework
+                    .data
+                    .global
+                    .get_mut::<Status>()
+                    .unwrap()
+                    .background_loading = true
+            }
+        }
+    }
+
+    /// updates the popup status when the dropdown is opened or closed
+    fn set_dropdown_opened(&mut self, framework: &mut FrameworkClean, opened: bool) {
+        // checked against `self` as there can be more than one search bar in a page
+        if self.opened == opened {
+            return;
+        }
+
+        self.opened = opened;
+        let status = framework.data.global.get_mut::<Status>().unwrap();
+        status.search_suggestions_opened = opened;
+        status.popup_opened = opened;
+        if opened {
+            // clears images that would otherwise be drawn over the dropdown
+            framework
+                .data
+                .state
+                .get_mut::<Tasks>()
+                .unwrap()
+                .priority
+                .push(Task::ClearPage);
+        } else {
+            status.render_image = true;
+            self.dropdown = None;
+        }
+    }
+
+    /// closes the dropdown and discards suggestions that are still loading
+    fn clear_suggestions(&mut self, framework: &mut FrameworkClean) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.suggestions.clear();
+        self.suggestion_hover = None;
+        self.waiting = false;
+        self.set_dropdown_opened(framework, false);
+    }
+
+    /// replaces the content with an older (or newer) past search, returns false if there are none
+    fn recall(&mut self, framework: &mut FrameworkClean, older: bool) -> bool {
+        let recall = match (self.recall, older) {
+            (None, true) => Some(0),
+            (Some(index), true) => Some(index + 1),
+            (None, false) => return false,
+            (Some(0), false) => None,
+            (Some(index), false) => Some(index - 1),
+        };
+
+        let content = match recall {
+            Some(index) => match framework
+                .data
+                .global
+                .get::<SearchHistory>()
+                .unwrap()
+                .recent()
+                .get(index)
+            {
+                Some(search) => search.query.clone(),
+                None => return false,
+            },
+            None => self.draft.clone(),
+        };
+
+        if self.recall.is_none() {
+            self.draft = self.text_field.content.clone();
+        }
+        self.recall = recall;
+        self.clear_suggestions(framework);
+        self.text_field.content = content;
+        let _ = self.text_field.last();
+        true
+    }
+
+    /// loads the search page (or the channel search page) of the query
+    fn search(&mut self, framework: &mut FrameworkClean, query: String) {
+        if query.is_empty() {
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Message(String::from("Search string must not be empty"));
+            framework
+                .data
+                .state
+                .get_mut::<Tasks>()
+                .unwrap()
+                .priority
+                .push(Task::RenderAll);
+            return;
+        }
+
+        self.clear_suggestions(framework);
+
+        if self.channel {
+            let page = match framework.data.state.get::<Page>().unwrap() {
+                Page::ChannelDisplay(page) => ChannelDisplayPage {
+                    r#type: ChannelDisplayPageType::Search,
+                    query,
+                    ..page.clone()
+                },
+                _ => return,
+            };
+            let tasks = framework.data.state.get_mut::<Tasks>().unwrap();
+            tasks
+                .priority
+                .push(Task::LoadPage(Page::ChannelDisplay(page)));
+            return;
+        }
+
+        let search = framework.data.state.get_mut::<Search>().unwrap();
+        search.query = query;
+        let search = search.clone();
+        let tasks = framework.data.state.get_mut::<Tasks>().unwrap();
+        tasks.priority.push(Task::LoadPage(Page::Search(search)));
+    }
+
+    /// suggestions that fit in the dropdown when it was last rendered
+    fn visible_suggestions(&self) -> &[String] {
+        let fits = self.dropdown.map_or(self.suggestions.len(), |dropdown| {
+            dropdown.height.saturating_sub(2) as usize
+        });
+        &self.suggestions[..fits.min(self.suggestions.len())]
+    }
+
+    /// renders the suggestions dropdown right below the search bar
+    fn render_dropdown(
+        &mut self,
+        frame: &mut tui::Frame<tui::backend::CrosstermBackend<std::io::Stdout>>,
+        framework: &FrameworkClean,
+        area: Rect,
+    ) {
+        let below = area.y + area.height;
+        let region = Rect {
+            x: area.x,
+            y: below,
+            width: area.width,
+            height: (self.suggestions.len() as u16 + 2)
+                .min(frame.size().height.saturating_sub(below)),
+        };
+
+        // `popup_area` gives the position relative to the region
+        let mut dropdown = match popup_area((100, 100), (2, 2), region) {
+            Ok(dropdown) => dropdown,
+            Err(_) => {
+                self.dropdown = None;
+                return;
+            }
+        };
+        dropdown.x += region.x;
+        dropdown.y += region.y;
+        self.dropdown = Some(dropdown);
+
+        // only suggestions that fit on screen are displayed
+        let visible = self.visible_suggestions();
+        let hover = self.suggestion_hover.filter(|hover| *hover < visible.len());
+
+        let appearance = framework.data.global.get::<AppearanceConfig>().unwrap();
+        let list = List::new(
+            visible
+                .iter()
+                .map(|suggestion| ListItem::new(suggestion.as_str()))
+                .collect::<Vec<_>>(),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(appearance.borders)
+                .border_style(Style::default().fg(appearance.colors.outline_selected)),
+        )
+        .style(Style::default().fg(appearance.colors.text))
+        .highlight_style(Style::default().fg(appearance.colors.text_secondary));
+
+        let mut state = ListState::default();
+        state.select(hover);
+
+        frame.render_widget(Clear, dropdown);
+        frame.render_stateful_widget(list, dropdown, &mut state);
+    }
 }
 
 impl FrameworkItem for SearchBar {
@@ -30,13 +321,23 @@ impl FrameworkItem for SearchBar {
         info: tui_additions::framework::ItemInfo,
     ) {
         if popup_render {
+            if info.selected && !self.suggestions.is_empty() {
+                self.render_dropdown(frame, framework, area);
+            }
             return;
         }
 
+        self.receive_suggestions(framework);
+        self.set_dropdown_opened(framework, info.selected && !self.suggestions.is_empty());
+
         let appearance = framework.data.global.get::<AppearanceConfig>().unwrap();
 
         let block = Block::default()
-            .title("Search YouTube")
+            .title(if self.channel {
+                "Search channel"
+            } else {
+                "Search YouTube"
+            })
             .title_alignment(Alignment::Center)
             .borders(Borders::ALL)
             .border_type(appearance.borders)
@@ -64,13 +365,25 @@ impl FrameworkItem for SearchBar {
         framework: &mut tui_additions::framework::FrameworkClean,
         _info: tui_additions::framework::ItemInfo,
     ) -> Result<(), Box<dyn Error>> {
-        let search = framework.data.state.get::<Search>().unwrap().query.clone();
+        let search = if self.channel {
+            match framework.data.state.get::<Page>().unwrap() {
+                Page::ChannelDisplay(page) => page.query.clone(),
+                _ => String::new(),
+            }
+        } else {
+            framework.data.state.get::<Search>().unwrap().query.clone()
+        };
         self.text_field.content = search;
         let _ = self.text_field.last();
 
         Ok(())
     }
 
+    fn deselect(&mut self, framework: &mut tui_additions::framework::FrameworkClean) -> bool {
+        self.clear_suggestions(framework);
+        true
+    }
+
     fn key_event(
         &mut self,
         framework: &mut tui_additions::framework::FrameworkClean,
@@ -105,6 +418,7 @@ impl FrameworkItem for SearchBar {
             content.chars().for_each(|c| {
                 let _ = self.text_field.push(c);
             });
+            self.content_changed(framework);
             framework
                 .data
                 .state
@@ -116,31 +430,69 @@ impl FrameworkItem for SearchBar {
         }
 
         let updated = match key.code {
-            KeyCode::Backspace => self.text_field.remove(self.text_field.cursor).is_ok(),
-            KeyCode::Char(c) => self.text_field.push(c).is_ok(),
+            KeyCode::Backspace => {
+                let updated = self.text_field.remove(self.text_field.cursor).is_ok();
+                if updated {
+                    self.content_changed(framework);
+                }
+                updated
+            }
+            KeyCode::Char(c) => {
+                let updated = self.text_field.push(c).is_ok();
+                if updated {
+                    self.content_changed(framework);
+                }
+                updated
+            }
+            // up and down moves through the suggestions when there are any
+            KeyCode::Up if !self.suggestions.is_empty() => {
+                self.suggestion_hover = match self.suggestion_hover {
+                    Some(0) | None => None,
+                    Some(hover) => Some(hover - 1),
+                };
+                true
+            }
+            KeyCode::Down if !self.suggestions.is_empty() => {
+                self.suggestion_hover = Some(match self.suggestion_hover {
+                    Some(hover) => {
+                        (hover + 1).min(self.visible_suggestions().len().saturating_sub(1))
+                    }
+                    None => 0,
+                });
+                true
+            }
+            // otherwise they recall past searches
+            KeyCode::Up if !self.channel => {
+                self.recall(framework, true) || self.text_field.first().is_ok()
+            }
+            KeyCode::Down if !self.channel && self.recall.is_some() => {
+                self.recall(framework, false)
+            }
             KeyCode::Up => self.text_field.first().is_ok(),
             KeyCode::Down => self.text_field.last().is_ok(),
             KeyCode::Right => self.text_field.right().is_ok(),
             KeyCode::Left => self.text_field.left().is_ok(),
             KeyCode::Enter => {
-                if self.text_field.content.is_empty() {
-                    *framework.data.global.get_mut::<Message>().unwrap() =
-                        Message::Message(String::from("Search string must not be empty"));
-                    framework
+                let query = self
+                    .suggestion_hover
+                    .and_then(|hover| self.visible_suggestions().get(hover).cloned())
+                    .unwrap_or_else(|| self.text_field.content.clone());
+
+                // a recalled search is searched again with the same filters
+                if let Some(index) = self.recall {
+                    let recalled = framework
                         .data
-                        .state
-                        .get_mut::<Tasks>()
+                        .global
+                        .get::<SearchHistory>()
                         .unwrap()
-                        .priority
-                        .push(Task::RenderAll);
-                    return Ok(());
+                        .recent()
+                        .get(index)
+                        .map(|search| (*search).clone());
+                    if let Some(recalled) = recalled.filter(|recalled| recalled.query == query) {
+                        *framework.data.state.get_mut::<Search>().unwrap() = recalled;
+                    }
                 }
-
-                let mut search = framework.data.state.get_mut::<Search>().unwrap();
-                search.query = self.text_field.content.clone();
-                let search = search.clone();
-                let tasks = framework.data.state.get_mut::<Tasks>().unwrap();
-                tasks.priority.push(Task::LoadPage(Page::Search(search)));
+                self.search(framework, query);
                 false
             }
             _ => false,
@@ -157,12 +509,26 @@ impl FrameworkItem for SearchBar {
 
     fn mouse_event(
         &mut self,
-        _framework: &mut tui_additions::framework::FrameworkClean,
+        framework: &mut tui_additions::framework::FrameworkClean,
         mut x: u16,
         y: u16,
-        _absolute_x: u16,
-        _absolute_y: u16,
+        absolute_x: u16,
+        absolute_y: u16,
     ) -> bool {
+        // clicking on a suggestion searches for it
+        if let Some(dropdown) = self.dropdown {
+            if dropdown.intersects(Rect::new(absolute_x, absolute_y, 1, 1)) {
+                // the borders of the dropdown are not suggestions
+                let index = absolute_y.saturating_sub(dropdown.y + 1) as usize;
+                let clicked = self.visible_suggestions().get(index).cloned();
+                if let Some(query) = clicked.filter(|_| absolute_y > dropdown.y) {
+                    self.text_field.content = query.clone();
+                    self.search(framework, query);
+                }
+                return true;
+            }
+        }
+
         if y != 1 || x == 0 {
             return false;
         }
@@ -66,15 +66,17 @@ impl SearchFilter {
                 .set_cursor_style(Style::default().fg(appearance.colors.outline_hover));
         }
 
-        self.right_textlist.selected = framework
-            .data
-            .state
-            .get::<Search>()
-            .unwrap()
-            .filters
-            .get_selected(self.left_textlist.selected);
+        let filters = &framework.data.state.get::<Search>().unwrap().filters;
+        let options = &self.right_options[self.left_textlist.selected];
+        match filters.get_selected(self.left_textlist.selected) {
+            Some(selected) => self.right_textlist.selected = selected,
+            // multi select options keeps the cursor where it is
+            None => {
+                self.right_textlist.selected = self.right_textlist.selected.min(options.len() - 1)
+            }
+        }
         self.right_textlist
-            .set_items(&self.right_options[self.left_textlist.selected])
+            .set_items(&filters.labels(self.left_textlist.selected, options))
             .unwrap();
     }
 }
@@ -130,13 +132,16 @@ impl FrameworkItem for SearchFilter {
 
             frame.render_widget(self.grid.clone(), area);
 
-            self.right_textlist.selected = framework
+            if let Some(selected) = framework
                 .data
                 .state
                 .get::<Search>()
                 .unwrap()
                 .filters
-                .get_selected(self.left_textlist.selected);
+                .get_selected(self.left_textlist.selected)
+            {
+                self.right_textlist.selected = selected;
+            }
 
             self.left_textlist.set_height(chunks[0].height);
             frame.render_widget(self.left_textlist.clone(), chunks[0]);
@@ -174,11 +179,9 @@ impl FrameworkItem for SearchFilter {
         self.right_textlist.set_border_type(appearance.borders);
         self.grid.set_border_type(appearance.borders);
 
-        self.update(framework);
-
         self.left_textlist.set_items(&self.left_options)?;
-        self.right_textlist
-            .set_items(&self.right_options[self.left_textlist.selected])?;
+        // sets the right list with labels, such as `[x]` for selected features
+        self.update(framework);
         self.grid
             .set_border_style(Style::default().fg(appearance.colors.outline_selected));
 
@@ -214,7 +217,7 @@ impl FrameworkItem for SearchFilter {
         }
 
         if let Page::Search(search_options) = framework.data.state.get::<Page>().unwrap() {
-            self.previous_state = Some(search_options.filters);
+            self.previous_state = Some(search_options.filters.clone());
         }
         true
     }
@@ -236,7 +239,7 @@ impl FrameworkItem for SearchFilter {
                 .get::<MainConfig>()
                 .unwrap()
                 .refresh_after_modifying_search_filters
-            || self.previous_state.unwrap() == search_options.filters
+            || self.previous_state.as_ref() == Some(&search_options.filters)
         {
             return true;
         }
@@ -273,6 +276,8 @@ impl FrameworkItem for SearchFilter {
             return Ok(());
         };
 
+        let multi_select =
+            self.current_hover && SearchFilters::is_multi_select(self.left_textlist.selected);
         let hovered_textlist = if self.current_hover {
             &mut self.right_textlist
         } else {
@@ -282,6 +287,12 @@ impl FrameworkItem for SearchFilter {
         let current_hover_before = self.current_hover;
 
         let updated = match action {
+            // multi select options are toggled without leaving the right list, left goes back
+            KeyAction::Select if multi_select => true,
+            KeyAction::MoveLeft if multi_select => {
+                self.current_hover = false;
+                true
+            }
             KeyAction::Select => {
                 self.current_hover = !self.current_hover;
                 true
@@ -302,7 +313,7 @@ impl FrameworkItem for SearchFilter {
                 .priority
                 .push(Task::RenderAll);
 
-            if current_hover_before {
+            if current_hover_before && (!multi_select || action == KeyAction::Select) {
                 framework
                     .data
                     .state
@@ -314,7 +325,7 @@ impl FrameworkItem for SearchFilter {
                         self.right_textlist.selected,
                         framework.data.global.get_mut::<Message>().unwrap(),
                     );
-            } else {
+            } else if !current_hover_before {
                 self.right_textlist
                     .set_items(&self.right_options[self.left_textlist.selected])?;
             }
@@ -376,6 +387,20 @@ impl FrameworkItem for SearchFilter {
         let y = (absolute_y - chunks[0].y) as usize;
 
         if y >= textlist.selected && y <= textlist.selected + 2 {
+            // clicking on the hovered option toggles it in multi select options
+            if !is_left_list && SearchFilters::is_multi_select(self.left_textlist.selected) {
+                framework
+                    .data
+                    .state
+                    .get_mut::<Search>()
+                    .unwrap()
+                    .filters
+                    .set_index(
+                        self.left_textlist.selected,
+                        self.right_textlist.selected,
+                        framework.data.global.get_mut::<Message>().unwrap(),
+                    );
+            }
             self.update(framework);
             return true;
         } else if y < textlist.selected {
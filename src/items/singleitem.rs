@@ -1,4 +1,7 @@
-use std::fs;
+use std::{
+    fs,
+    sync::{mpsc, Arc, Mutex},
+};
 
 use super::ItemInfo;
 use crate::{
@@ -61,6 +64,8 @@ pub struct SinglePlaylistItem {
     pub commands: Vec<(String, String)>,
     pub is_commands_view: bool,
     pub hovered_video: ItemInfo,
+    /// receives the rest of the playlist videos if they are still loading
+    pub pending: Option<Arc<Mutex<mpsc::Receiver<PlaylistBatch>>>>,
 }
 
 impl SingleVideoItem {
@@ -264,6 +269,7 @@ impl SinglePlaylistItem {
             commands,
             hovered_video,
             is_commands_view: true,
+            pending: None,
         }
     }
 
@@ -474,6 +480,100 @@ impl SingleItem {
         }
     }
 
+    /// appends playlist videos that are loaded in the background, and shows the loading progress
+    fn receive_pending(&mut self, framework: &mut FrameworkClean) {
+        let singleplaylistitem = match &mut self.r#type {
+            SingleItemType::Playlist(singleplaylistitem) => singleplaylistitem,
+            _ => return,
+        };
+        let fullplaylist = match &mut self.item {
+            Some(Item::FullPlaylist(fullplaylist)) => fullplaylist,
+            _ => return,
+        };
+        let pending = match &singleplaylistitem.pending {
+            Some(pending) => pending.clone(),
+            None => return,
+        };
+
+        let mut received = false;
+        let mut finished = false;
+        loop {
+            match pending.lock().unwrap().try_recv() {
+                Ok(Ok(videos)) => {
+                    singleplaylistitem.videos_view.items.extend(
+                        videos
+                            .iter()
+                            .map(|video| video.minivideo().unwrap().title.clone()),
+                    );
+                    fullplaylist.videos.extend(videos);
+                    received = true;
+                }
+                Ok(Err(e)) => {
+                    *framework.data.global.get_mut::<Message>().unwrap() =
+                        Message::Error(format!("Failed to load the rest of the playlist: {e}"));
+                    singleplaylistitem.pending = None;
+                    return;
+                }
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                }
+            }
+        }
+
+        let loaded = fullplaylist.videos.len();
+        let total = fullplaylist.video_count;
+
+        if received || finished {
+            set_envs(
+                singleplaylistitem
+                    .inflate_load(self.item.as_ref().unwrap())
+                    .into_iter(),
+                &mut framework.data.state.get_mut::<StateEnvs>().unwrap().0,
+            );
+        }
+
+        if !finished {
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Message(format!("Loading playlist videos... ({loaded}/{total})"));
+            framework
+                .data
+                .global
+                .get_mut::<Status>()
+                .unwrap()
+                .background_loading = true;
+            return;
+        }
+
+        singleplaylistitem.pending = None;
+        *framework.data.global.get_mut::<Message>().unwrap() =
+            Message::Success(format!("Loaded {loaded} videos"));
+
+        // update the saved playlist so it does not need to be loaded again
+        let max_watch_history = framework
+            .data
+            .global
+            .get::<MainConfig>()
+            .unwrap()
+            .max_watch_history;
+        let watch_history = framework.data.global.get_mut::<WatchHistory>().unwrap();
+        if watch_history
+            .push(self.item.clone().unwrap(), Some(max_watch_history))
+            .and_then(|_| watch_history.save())
+            .is_err()
+        {
+            *framework.data.global.get_mut::<Message>().unwrap() =
+                Message::Error(String::from("Failed to save the loaded playlist"));
+        }
+        framework
+            .data
+            .global
+            .get_mut::<LabelsRevision>()
+            .unwrap()
+            .bump();
+    }
+
     /// handle enter presses
     fn select_at_cursor(
         &mut self,
@@ -546,11 +646,13 @@ impl FrameworkItem for SingleItem {
         popup_render: bool,
         info: tui_additions::framework::ItemInfo,
     ) {
-        let status = framework.data.global.get::<Status>().unwrap();
         if popup_render {
             return;
         }
 
+        self.receive_pending(framework);
+        let status = framework.data.global.get::<Status>().unwrap();
+
         let appearance = framework.data.global.get::<AppearanceConfig>().unwrap();
 
         if self.item.is_none() {
@@ -666,9 +768,8 @@ impl FrameworkItem for SingleItem {
         // load items using the invidious api
         // gets the item that it needs to load from `data.state.Page`
         let (item, r#type) = match r#type {
-            SingleItemPage::Video(id) => {
-                let home_dir = home_dir().unwrap();
-                let path = home_dir.join(format!(".cache/youtube-tui/info/{id}.json"));
+            SingleItemPage::Video(id, _) => {
+                let path = cache_dir().join(format!("info/{id}.json"));
 
                 let video = if path.exists() {
                     serde_json::from_str(&fs::read_to_string(path)?)?
@@ -689,11 +790,10 @@ impl FrameworkItem for SingleItem {
                 )
             }
             SingleItemPage::Playlist(id) => {
-                let path = home_dir()
-                    .unwrap()
-                    .join(format!(".cache/youtube-tui/info/{id}.json"));
+                let path = cache_dir().join(format!("info/{id}.json"));
 
-                let playlist = if path.exists() {
+                let cached = path.exists();
+                let playlist: Item = if cached {
                     serde_json::from_str(&fs::read_to_string(path)?)?
                 } else {
                     load_playlist(
@@ -702,17 +802,37 @@ impl FrameworkItem for SingleItem {
                         mainconfig,
                     )?
                 };
-                let r#type = SingleItemType::Playlist(
-                    SinglePlaylistItem::new(
-                        framework.data.global.get::<CommandsConfig>().unwrap(),
-                        mainconfig,
-                        id,
-                        &playlist.fullplaylist().unwrap().videos,
-                    )
-                    .into(),
+                let mut singleplaylistitem = SinglePlaylistItem::new(
+                    framework.data.global.get::<CommandsConfig>().unwrap(),
+                    mainconfig,
+                    id,
+                    &playlist.fullplaylist()?.videos,
                 );
 
-                (playlist, r#type)
+                // the api only returns one page of videos at a time, the rest of the playlist
+                // are loaded in the background (also for playlists cached before this is added)
+                let fullplaylist = playlist.fullplaylist()?;
+                if fullplaylist.videos.len() < fullplaylist.video_count as usize {
+                    singleplaylistitem.pending = Some(Arc::new(Mutex::new(load_playlist_rest(
+                        &framework.data.global.get::<InvidiousClient>().unwrap().0,
+                        id,
+                        fullplaylist.videos.len(),
+                        if cached { 1 } else { 2 },
+                        fullplaylist.video_count as usize,
+                        mainconfig,
+                    ))));
+                    framework
+                        .data
+                        .global
+                        .get_mut::<Status>()
+                        .unwrap()
+                        .background_loading = true;
+                }
+
+                (
+                    playlist,
+                    SingleItemType::Playlist(singleplaylistitem.into()),
+                )
             }
         };
 
@@ -737,6 +857,12 @@ impl FrameworkItem for SingleItem {
             let watch_history = framework.data.global.get_mut::<WatchHistory>().unwrap();
             watch_history.push(item, Some(max_watch_history))?;
             watch_history.save()?;
+            framework
+                .data
+                .global
+                .get_mut::<LabelsRevision>()
+                .unwrap()
+                .bump();
         }
 
         let mainconfig = framework.data.global.get::<MainConfig>().unwrap();
@@ -917,6 +1043,7 @@ impl FrameworkItem for SingleItem {
                                             .minivideo()?
                                             .id
                                             .clone(),
+                                        None,
                                     ))));
                             }
 
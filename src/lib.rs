@@ -8,3 +8,7 @@ mod run;
 pub use run::*;
 mod exit;
 pub use exit::*;
+mod cli;
+#[cfg(test)]
+mod headless;
+pub use cli::*;
@@ -3,10 +3,19 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use std::{env, error::Error, io};
+use std::{env, error::Error, io, process};
 use tui::{backend::CrosstermBackend, Terminal};
 use tui_additions::framework::{Framework, State};
-use youtube_tui::{exit, global::functions::text_command, init, run};
+#[cfg(unix)]
+use youtube_tui::global::functions::send_remote_command;
+use youtube_tui::{
+    exit,
+    global::functions::{
+        check_configs, config_dir, lookup_command, parse_url, set_cache_dir, set_config_dir,
+        set_data_dir, set_profile, text_command,
+    },
+    init, run, CliArgs,
+};
 
 // stuff happening:
 //  1. setup the terminal
@@ -14,13 +23,88 @@ use youtube_tui::{exit, global::functions::text_command, init, run};
 //  3. restore the terminal
 //  4. unwrap errors
 fn main() -> Result<(), Box<dyn Error>> {
-    let args = env::args().skip(1).collect::<Vec<_>>().join(" ");
+    let mut cli = match CliArgs::parse(env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(2);
+        }
+    };
+
+    if let Some(path) = &cli.config_dir {
+        set_config_dir(path.clone());
+    }
+    if let Some(path) = &cli.cache_dir {
+        set_cache_dir(path.clone());
+    }
+    if let Some(path) = &cli.data_dir {
+        set_data_dir(path.clone());
+    }
+
+    // send the command to the running instance instead of launching the TUI
+    if let Some(command) = &cli.remote {
+        #[cfg(unix)]
+        match send_remote_command(command) {
+            Ok(status) => println!("{status}"),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            eprintln!("`--remote {command}` is only supported on unix");
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Err(e) = set_profile(cli.profile.clone()) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    // a bare url is opened as whatever it points to
+    if !cli.command.contains(' ')
+        && (cli.command.contains('/') || cli.command.starts_with('@'))
+        && parse_url(&cli.command).is_ok()
+    {
+        cli.command = format!("open {}", cli.command);
+    }
 
-    if let Some(s) = text_command(&args) {
+    // print items fetched by lookup commands without launching the TUI
+    if let Some(res) = lookup_command(&cli) {
+        match res {
+            Ok(s) => println!("{s}"),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if let Some(s) = text_command(&cli.command) {
         println!("{s}");
         return Ok(());
     }
 
+    // config files are checked before the TUI starts, so that the errors can be read after
+    // exiting, the defaults are used for invalid files
+    let issues = check_configs();
+    if cli.command == "check-config" {
+        if issues.is_empty() {
+            println!("All config files in {} are valid", config_dir().display());
+            return Ok(());
+        }
+        issues.iter().for_each(|issue| eprintln!("{issue}"));
+        process::exit(1);
+    }
+    if !issues.is_empty() {
+        eprintln!("Invalid config files, the defaults are used for them until they are fixed:");
+        issues.iter().for_each(|issue| eprintln!("{issue}"));
+    }
+
     let state = State(Vec::new());
     let mut framework = Framework::new(state);
 
@@ -31,11 +115,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     let res = (|| -> Result<(), Box<dyn Error>> {
-        init(
-            &mut framework,
-            &mut terminal,
-            if args.is_empty() { None } else { Some(&args) },
-        )?;
+        init(&mut framework, &mut terminal, cli)?;
         run(&mut terminal, &mut framework)?;
         Ok(())
     })();
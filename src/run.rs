@@ -1,6 +1,6 @@
 use crossterm::event::{self, Event, MouseButton, MouseEventKind};
-use std::{error::Error, io::Stdout};
-use tui::{backend::CrosstermBackend, Terminal};
+use std::{error::Error, time::Duration};
+use tui::Terminal;
 use tui_additions::{
     framework::{Framework, FrameworkDirection},
     widgets::TextField,
@@ -8,12 +8,15 @@ use tui_additions::{
 
 use crate::{
     config::*,
-    global::{functions::*, structs::*},
+    global::{functions::*, structs::*, traits::FrameworkBackend},
 };
 
+/// how often the event loop stops waiting for events to run background checks
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// the main event loop of the program
-pub fn run(
-    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+pub fn run<B: FrameworkBackend>(
+    terminal: &mut Terminal<B>,
     framework: &mut Framework,
 ) -> Result<(), Box<dyn Error>> {
     loop {
@@ -23,12 +26,39 @@ pub fn run(
             continue;
         }
 
+        // reply to programs that sent commands through the control socket, and run new commands
+        // from it
+        #[cfg(unix)]
+        if handle_control_socket(framework) {
+            continue;
+        }
+
         // exits the function is `.exit` is true - a way for items/commands to exit the program
         if framework.data.global.get::<Status>().unwrap().exit {
             break;
         }
         *framework.data.global.get_mut::<Message>().unwrap() = Message::None;
 
+        // wait for an event, while checking on the background refreshers and config files every
+        // now and then
+        refresh_subscriptions(framework);
+        refresh_saved_searches(framework);
+        watch_configs(framework);
+        if !event::poll(POLL_INTERVAL)? {
+            let status = framework.data.global.get_mut::<Status>().unwrap();
+            if status.background_loading {
+                status.background_loading = false;
+                framework
+                    .data
+                    .state
+                    .get_mut::<Tasks>()
+                    .unwrap()
+                    .priority
+                    .push(Task::RenderAll);
+            }
+            continue;
+        }
+
         match event::read()? {
             Event::Mouse(mouse)
                 if framework
@@ -47,14 +77,15 @@ pub fn run(
                 let status = framework.data.global.get_mut::<Status>().unwrap();
                 status.command_capture = None;
 
-                // check if the search filter popup is clicked
-                let mut searchfilter_clicked = false;
-                if status.search_filter_opened {
+                // check if the search filter popup or the search suggestions dropdown is clicked
+                let mut popup_clicked = false;
+                if status.search_filter_opened || status.search_suggestions_opened {
                     let (mut frameworkclean, state) = framework.split_clean();
                     for row in state.0.iter_mut() {
                         for item in row.items.iter_mut() {
                             match item.item.r#type() {
-                                "youtube_tui::items::searchfilters::SearchFilter" => {
+                                "youtube_tui::items::searchfilters::SearchFilter"
+                                | "youtube_tui::items::searchbar::SearchBar" => {
                                     if item.item.mouse_event(
                                         &mut frameworkclean,
                                         0,
@@ -62,7 +93,7 @@ pub fn run(
                                         mouse.column,
                                         mouse.row,
                                     ) {
-                                        searchfilter_clicked = true;
+                                        popup_clicked = true;
                                         break;
                                     }
                                 }
@@ -70,13 +101,13 @@ pub fn run(
                             }
                         }
 
-                        if searchfilter_clicked {
+                        if popup_clicked {
                             break;
                         }
                     }
                 }
 
-                let updated = if searchfilter_clicked {
+                let updated = if popup_clicked {
                     true
                 } else {
                     framework.mouse_event(mouse.column, mouse.row)